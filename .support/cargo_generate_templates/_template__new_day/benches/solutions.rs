@@ -0,0 +1,29 @@
+//! Benchmarks of {{ project-name | upper_camel_case }}'s parsing & solutions, on its example & full inputs. (`cargo bench -p {{ project-name }}`)
+//!
+//! Full inputs are read from the input directory; a part whose full input is missing is only benchmarked on its
+//! example. `cargo xtask bench` keeps the results as JSON baselines, and reports regressions between runs.
+
+use aoc_core::{BenchInput, Part, bench_inputs};
+use divan::{Bencher, black_box};
+use {{ project-name | snake_case }}::{ {{ project-name | upper_camel_case }}, parse_input};
+
+fn main() {
+        divan::main();
+}
+
+#[divan::bench(args = bench_inputs::<{{ project-name | upper_camel_case }}>(Part::Part1))]
+fn parse(bencher: Bencher, input: &BenchInput) {
+        bencher.bench(|| parse_input(black_box(&input.text)));
+}
+
+// Once the parts are solved (until then they panic, at `todo!`), import `process_part1` & `process_part2` and add:
+//
+// #[divan::bench(args = bench_inputs::<{{ project-name | upper_camel_case }}>(Part::Part1))]
+// fn part1(bencher: Bencher, input: &BenchInput) {
+//         bencher.bench(|| process_part1(black_box(&input.text)));
+// }
+//
+// #[divan::bench(args = bench_inputs::<{{ project-name | upper_camel_case }}>(Part::Part2))]
+// fn part2(bencher: Bencher, input: &BenchInput) {
+//         bencher.bench(|| process_part2(black_box(&input.text)));
+// }
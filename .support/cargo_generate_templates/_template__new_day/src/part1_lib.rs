@@ -2,13 +2,18 @@
 
 use tracing::{Level, instrument};
 
-#[expect(unused)]
 use crate::{Result, parse::parse_input};
 
 #[instrument(skip_all, ret(level = Level::DEBUG))]
+#[expect(clippy::let_unit_value)] // until `parse_input` returns a real type
 pub fn process_part1(input: &str) -> Result<u64> {
         tracing::event!(Level::TRACE, %input);
-        // let _parsed_input = parse_input(input)?;
+        let parsed_input = parse_input(input)?;
+        solve_part1(&parsed_input)
+}
+
+#[instrument(skip_all, ret(level = Level::DEBUG))]
+pub fn solve_part1(_parsed_input: &()) -> Result<u64> {
         todo!();
 }
 
@@ -36,9 +41,10 @@ pub fn process_part1(input: &str) -> Result<u64> {
 
 //         // /// Test's expected value to be populated after solution verification.
 //         // #[test]
+//         // #[ignore = "needs the private full input; run with `just test-full`"]
 //         // #[instrument]
 //         // fn test_process_problem_input() -> Result<()> {
-//         //         let input = FINAL_INPUT;
+//         //         let input = &FINAL_INPUT.load()?;
 //         //         let expected = todo!();
 //         //         assert_eq!(process_part1(input)?, expected);
 //         //         Ok(())
@@ -60,6 +60,8 @@ pub enum ErrKind{{ project-name | upper_camel_case }} {
         EnvError {
                 source: tracing_subscriber::filter::FromEnvError,
         },
+        #[display("input error: {}", source)]
+        Input { source: aoc_core::Error },
         #[display("io error: {}", source)]
         Io { source: io::Error },
         #[display("Error setting tracing subscriber default: {}", source)]
@@ -0,0 +1,206 @@
+//! `aoc batch`: one day & part, over every input file in a directory, as one table.
+//!
+//! Files run in parallel (rayon), each isolated (see [`run_isolated`]) so a panicking or hanging input is reported
+//! rather than ending the batch. Hidden files (e.g. `.gitkeep`) and subdirectories are skipped.
+//! Each file is identified by the start of its [`input_hash`], so copies of one input can be told apart from edits.
+//! The table is CSV or markdown, to stdout or a file.
+
+use std::{fmt::Write as _,
+          fs, io,
+          path::{Path, PathBuf},
+          time::Duration};
+
+use aoc_core::{DynSolution, ErrKindAoc, IsolatedRun, Outcome, Part, Result, Solved, input_hash, normalize_input,
+               run_isolated};
+use clap::ValueEnum;
+use derive_more::derive::Display;
+use rayon::prelude::*;
+use tracing::{self as tea, instrument};
+
+use crate::run_all::{short_hash, silence_panics};
+
+/// How a batch table is written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Display)]
+pub enum TableFormat {
+        /// Markdown table.
+        #[default]
+        #[display("markdown")]
+        Markdown,
+        /// Comma separated values, with a header row.
+        #[display("csv")]
+        Csv,
+}
+
+/// One input file's run.
+#[derive(Debug)]
+pub struct Row {
+        /// Path of the file, relative to the batch directory.
+        pub file:       PathBuf,
+        /// [`input_hash`] of the file's (normalized) text; `None` if it could not be read.
+        pub input_hash: Option<String>,
+        pub run:        IsolatedRun<Solved>,
+}
+impl Row {
+        /// Table cells: file, short hash, status, answer, parse, solve & total time (seconds), error (first line only).
+        fn cells(&self) -> [String; 8] {
+                let (answer, parse_secs, solve_secs, error) = match &self.run.outcome {
+                        Outcome::Ok(solved) => {
+                                (solved.answer.clone(), secs(solved.parse_time), secs(solved.solve_time), String::new())
+                        }
+                        outcome => {
+                                let detail = outcome.detail();
+                                (String::new(), String::new(), String::new(), first_line(&detail).to_string())
+                        }
+                };
+                [
+                        self.file.display().to_string(),
+                        short_hash(self.input_hash.as_deref()).to_string(),
+                        self.run.outcome.to_string(),
+                        answer,
+                        parse_secs,
+                        solve_secs,
+                        secs(self.run.elapsed),
+                        error,
+                ]
+        }
+}
+
+const HEADERS: [&str; 8] = ["file", "hash", "status", "answer", "parse_secs", "solve_secs", "total_secs", "error"];
+
+/// Run `part` of `solution` on every (non-hidden) file in `dir`, in parallel; rows are sorted by file name.
+///
+/// The panic hook is silenced for the duration, as panics are reported in the table instead.
+#[instrument(skip(solution), fields(day = solution.day()))]
+pub fn run_batch(solution: &'static dyn DynSolution, part: Part, dir: &Path, timeout: Duration) -> Result<Vec<Row>> {
+        let files = input_files(dir)?;
+        tea::info!(count = files.len(), "running batch");
+        let _silenced = silence_panics();
+        Ok(files.into_par_iter()
+                .map(|file| {
+                        let path = dir.join(&file);
+                        let (input_hash, run) = match fs::read_to_string(&path) {
+                                Ok(input) => (
+                                        Some(input_hash(&normalize_input(&input).0)),
+                                        run_isolated(timeout, move || solution.solve(part, &input)),
+                                ),
+                                Err(source) => (None, IsolatedRun {
+                                        outcome: Outcome::Err(ErrKindAoc::InputFileRead { path, source }.to_string()),
+                                        elapsed: Duration::ZERO,
+                                }),
+                        };
+                        Row { file, input_hash, run }
+                })
+                .collect())
+}
+
+/// Names of the regular, non-hidden files in `dir`, sorted.
+fn input_files(dir: &Path) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for entry in
+                fs::read_dir(dir).map_err(|source| ErrKindAoc::InputFileRead { path: dir.to_path_buf(), source })?
+        {
+                let entry = entry?;
+                let name = entry.file_name();
+                if entry.file_type()?.is_file() && !name.to_string_lossy().starts_with('.') {
+                        files.push(PathBuf::from(name));
+                }
+        }
+        files.sort();
+        Ok(files)
+}
+
+/// Write rows as a table in the given format.
+pub fn write_table(rows: &[Row], format: TableFormat, writer: impl io::Write) -> Result<()> {
+        match format {
+                TableFormat::Csv => {
+                        let mut csv = csv::Writer::from_writer(writer);
+                        csv.write_record(HEADERS).map_err(io::Error::from)?;
+                        for row in rows {
+                                csv.write_record(row.cells()).map_err(io::Error::from)?;
+                        }
+                        csv.flush()?;
+                }
+                TableFormat::Markdown => {
+                        let mut writer = writer;
+                        write!(writer, "{}", markdown(rows))?;
+                }
+        }
+        Ok(())
+}
+
+/// Rows as a markdown table. (Cells are cut to their first line, with `|` escaped.)
+fn markdown(rows: &[Row]) -> String {
+        let mut table = format!("| {} |\n|{}\n", HEADERS.join(" | "), " --- |".repeat(HEADERS.len()));
+        for row in rows {
+                let cells = row.cells().map(|cell| first_line(&cell).replace('|', r"\|"));
+                writeln!(table, "| {} |", cells.join(" | ")).expect("writing to a String cannot fail");
+        }
+        table
+}
+
+fn first_line(text: &str) -> &str {
+        text.lines().next().unwrap_or_default()
+}
+
+/// Duration in seconds, to microsecond precision.
+fn secs(duration: Duration) -> String {
+        format!("{:.6}", duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+        use crate::registry;
+
+        /// A fresh, empty directory under the system temp dir.
+        fn scratch_dir(name: &str) -> PathBuf {
+                let dir = std::env::temp_dir().join(format!("aoc-batch-{name}-{}", std::process::id()));
+                let _ = fs::remove_dir_all(&dir);
+                fs::create_dir_all(&dir).expect("temp dir is writable");
+                dir
+        }
+
+        #[test]
+        fn batch_over_directory() -> Result<()> {
+                let dir = scratch_dir("day01");
+                fs::write(dir.join("b_example.txt"), day01::EXAMPLE_INPUT_1)?;
+                fs::write(dir.join("a_bad.txt"), "not a list of numbers")?;
+                fs::write(dir.join(".gitkeep"), "")?;
+                fs::create_dir(dir.join("nested"))?;
+                let day01 = registry::find(1).expect("day 1 is registered");
+
+                let rows = run_batch(day01, Part::Part1, &dir, Duration::from_secs(10))?;
+                let files: Vec<_> = rows.iter().map(|row| row.file.display().to_string()).collect();
+                assert_eq!(files, ["a_bad.txt", "b_example.txt"]);
+                assert!(!rows[0].run.outcome.is_ok());
+                let example_hash = input_hash(&normalize_input(day01::EXAMPLE_INPUT_1).0);
+                assert_eq!(rows[1].input_hash.as_deref(), Some(example_hash.as_str()));
+                assert_eq!(rows[1].cells()[..4], ["b_example.txt", &example_hash[..12], "OK", "11"]);
+
+                let mut csv = Vec::new();
+                write_table(&rows, TableFormat::Csv, &mut csv)?;
+                let csv = String::from_utf8(csv).expect("csv is utf-8");
+                assert!(csv.starts_with("file,hash,status,answer,parse_secs,solve_secs,total_secs,error\n"), "{csv}");
+                assert_eq!(csv.lines().count(), 3);
+
+                let markdown = markdown(&rows);
+                assert_eq!(markdown.lines().nth(1), Some("| --- | --- | --- | --- | --- | --- | --- | --- |"));
+                assert!(markdown
+                        .lines()
+                        .nth(3)
+                        .is_some_and(|line| line
+                                .starts_with(&format!("| b_example.txt | {} | OK | 11 |", &example_hash[..12]))));
+                fs::remove_dir_all(&dir)?;
+                Ok(())
+        }
+
+        #[test]
+        fn batch_missing_directory() {
+                let day01 = registry::find(1).expect("day 1 is registered");
+                let missing = std::env::temp_dir().join("aoc-batch-does-not-exist");
+                assert!(run_batch(day01, Part::Part1, &missing, Duration::from_secs(1)).is_err());
+        }
+}
@@ -0,0 +1,68 @@
+//! `aoc encrypt` / `aoc decrypt`: move days' private inputs between the input directory and their encrypted copies
+//! in `crates/dayNN/data/`.
+
+use std::path::Path;
+
+use aoc_core::{ErrKindAoc, InputKey, Result, decrypt_inputs, encrypt_inputs, stored_input_dir};
+
+use crate::registry;
+
+/// Days named, or every registered day if none are.
+fn days_or_all(days: &[u8]) -> Result<Vec<u8>> {
+        match days.is_empty() {
+                true => Ok(registry::SOLUTIONS.iter().map(|solution| solution.day()).collect()),
+                false => days
+                        .iter()
+                        .map(|&day| {
+                                registry::find(day)
+                                        .map(|_| day)
+                                        .ok_or_else(|| ErrKindAoc::UnregisteredDay { day }.into())
+                        })
+                        .collect(),
+        }
+}
+
+/// The key in `AOC_INPUT_KEY`, which encrypting & decrypting need.
+fn key() -> Result<InputKey> {
+        Ok(InputKey::from_env()?.ok_or(ErrKindAoc::NoInputKey)?)
+}
+
+/// Encrypt the days' inputs from `input_dir` into their crates, reporting each file written.
+pub fn encrypt(days: &[u8], input_dir: &Path) -> Result<()> {
+        let key = key()?;
+        for day in days_or_all(days)? {
+                let plain_dir = input_dir.join(format!("day{day:02}"));
+                for path in encrypt_inputs(&key, day, &plain_dir, &stored_input_dir(day))? {
+                        println!("encrypted {}", path.display());
+                }
+        }
+        Ok(())
+}
+
+/// Decrypt the days' inputs from their crates into `input_dir`, reporting each file written.
+pub fn decrypt(days: &[u8], input_dir: &Path, force: bool) -> Result<()> {
+        let key = key()?;
+        for day in days_or_all(days)? {
+                let plain_dir = input_dir.join(format!("day{day:02}"));
+                for path in decrypt_inputs(&key, day, &stored_input_dir(day), &plain_dir, force)? {
+                        println!("decrypted {}", path.display());
+                }
+        }
+        Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        #[test]
+        fn days_default_to_registered() -> Result<()> {
+                assert_eq!(days_or_all(&[6])?, [6]);
+                assert_eq!(days_or_all(&[])?.len(), registry::SOLUTIONS.len());
+                assert!(days_or_all(&[25]).is_err());
+                Ok(())
+        }
+}
@@ -0,0 +1,151 @@
+//! `aoc launch` (or bare `aoc` in a terminal): pick a day, part, input & log level from menus, then run it.
+//!
+//! Menus start on the `aoc.toml` defaults. Escape (or `q`) at any menu quits without running anything.
+
+use std::{io, path::PathBuf, time::Duration};
+
+use aoc_core::{Config, DynSolution, InputKind, Parts, Result};
+use dialoguer::{Input, Select, theme::ColorfulTheme};
+use indicatif::ProgressBar;
+use tracing::level_filters::LevelFilter;
+
+use crate::registry;
+
+/// Choices made in the launcher.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Launch {
+        pub day:   u8,
+        pub parts: Parts,
+        pub input: LaunchInput,
+        pub log:   LevelFilter,
+}
+
+/// Input picked in the launcher.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchInput {
+        /// One of the day's own inputs.
+        Kind(InputKind),
+        /// A file, by path.
+        File(PathBuf),
+}
+
+const PARTS: [Parts; 3] = [Parts::Part1, Parts::Part2, Parts::Both];
+const INPUT_KINDS: [InputKind; 3] = [InputKind::Example, InputKind::Full, InputKind::Custom];
+const LOG_LEVELS: [LevelFilter; 6] = [
+        LevelFilter::OFF,
+        LevelFilter::ERROR,
+        LevelFilter::WARN,
+        LevelFilter::INFO,
+        LevelFilter::DEBUG,
+        LevelFilter::TRACE,
+];
+
+/// Walk through the menus; `None` if one was cancelled.
+pub fn pick(config: &Config) -> Result<Option<Launch>> {
+        let theme = ColorfulTheme::default();
+        let days: Vec<String> = registry::SOLUTIONS.iter().map(|&solution| day_item(solution)).collect();
+        let Some(day_idx) = select(&theme, "Day", &days, days.len().saturating_sub(1))? else {
+                return Ok(None);
+        };
+        let day = registry::SOLUTIONS[day_idx].day();
+        let day_config = config.day(day);
+
+        let parts: Vec<String> = PARTS.iter().map(|&parts| parts_item(parts)).collect();
+        let Some(part_idx) = select(&theme, "Part", &parts, 0)? else {
+                return Ok(None);
+        };
+
+        let mut inputs: Vec<String> = INPUT_KINDS.iter().map(InputKind::to_string).collect();
+        inputs.push("file…".to_string());
+        let default_input = day_config.input.unwrap_or(InputKind::Example);
+        let Some(input_idx) = select(&theme, "Input", &inputs, index_of(&INPUT_KINDS, &default_input))? else {
+                return Ok(None);
+        };
+        let input = match INPUT_KINDS.get(input_idx) {
+                Some(&kind) => LaunchInput::Kind(kind),
+                None => {
+                        let path: String = Input::with_theme(&theme)
+                                .with_prompt("Input file")
+                                .validate_with(|path: &String| match PathBuf::from(path).is_file() {
+                                        true => Ok(()),
+                                        false => Err("no such file"),
+                                })
+                                .interact_text()
+                                .map_err(io_error)?;
+                        LaunchInput::File(PathBuf::from(path))
+                }
+        };
+
+        let levels: Vec<String> = LOG_LEVELS
+                .iter()
+                .map(|level| level.to_string().to_lowercase())
+                .collect();
+        let default_level = day_config.log.level.unwrap_or(LevelFilter::WARN);
+        let Some(level_idx) = select(&theme, "Log level", &levels, index_of(&LOG_LEVELS, &default_level))? else {
+                return Ok(None);
+        };
+
+        Ok(Some(Launch { day, parts: PARTS[part_idx], input, log: LOG_LEVELS[level_idx] }))
+}
+
+/// One menu, starting on `default`; `None` if cancelled.
+fn select(theme: &ColorfulTheme, prompt: &str, items: &[String], default: usize) -> Result<Option<usize>> {
+        Ok(Select::with_theme(theme)
+                .with_prompt(prompt)
+                .items(items)
+                .default(default)
+                .interact_opt()
+                .map_err(io_error)?)
+}
+
+/// Terminal errors are all I/O errors.
+fn io_error(err: dialoguer::Error) -> io::Error {
+        let dialoguer::Error::IO(err) = err;
+        err
+}
+
+/// Menu line for a day. (e.g. `Day 06: Guard Gallivant`)
+fn day_item(solution: &dyn DynSolution) -> String {
+        format!("Day {:02}: {}", solution.day(), solution.title())
+}
+
+/// Menu line for part(s). (e.g. `part 1`, `both parts`)
+pub fn parts_item(parts: Parts) -> String {
+        match parts.single() {
+                Some(part) => format!("part {part}"),
+                None => "both parts".to_string(),
+        }
+}
+
+/// Position of `item` in `items`, else the first.
+fn index_of<T: PartialEq>(items: &[T], item: &T) -> usize {
+        items.iter().position(|candidate| candidate == item).unwrap_or_default()
+}
+
+/// Spinner on stderr while `job` runs, cleared once it is done.
+pub fn with_spinner<T>(message: String, job: impl FnOnce() -> T) -> T {
+        let spinner = ProgressBar::new_spinner().with_message(message);
+        spinner.enable_steady_tick(Duration::from_millis(100));
+        let out = job();
+        spinner.finish_and_clear();
+        out
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        #[test]
+        fn menu_items() {
+                let day06 = registry::find(6).expect("day 6 is registered");
+                assert_eq!(day_item(day06), "Day 06: Guard Gallivant");
+                assert_eq!(parts_item(Parts::Part2), "part 2");
+                assert_eq!(parts_item(Parts::Both), "both parts");
+                assert_eq!(index_of(&INPUT_KINDS, &InputKind::Full), 1);
+                assert_eq!(index_of(&LOG_LEVELS, &LevelFilter::WARN), 2);
+                assert_eq!(index_of(&[1, 2], &3), 0);
+        }
+}
@@ -0,0 +1,317 @@
+//! CLI interface to run any registered day of Advent of Code 2024.
+//!
+//! ```zsh
+//! aoc 6 2 full    # day 6, part 2, full input
+//! aoc 6 2 --input my_input.txt    # day 6, part 2, input from a file (`-` for stdin)
+//! aoc             # in a terminal: pick day, part, input & log level from menus (else as `aoc list`)
+//! aoc list        # registered days, with any variants of their parts
+//! aoc list --format json    # as above, as JSON (day, title & variants)
+//! aoc 6 2 full --format json    # as above, as JSON (answer, input hash, timings, or error)
+//! aoc 6 2 full --expect 1562     # as above, exiting non-zero (see `RunExit`) unless answered with 1562
+//! aoc 6 both full --expect 4711 --expect 1562    # both parts from a single parse
+//! aoc 6 1 full --all-variants    # every implementation of day 6, part 1, failing unless all agree
+//! aoc 6 1 full --variant hashset-loop-check    # one of them
+//! aoc all         # every day, part & input, as a table
+//! aoc batch 6 2 stress/ --table csv --out results.csv    # day 6, part 2, on every file in `stress/`
+//! aoc watch 6 2 example    # rebuild & re-run day 6, part 2 on every change to its sources or inputs
+//! aoc verify      # as `all`, failing on any answer that differs from `data/answers.toml`
+//! aoc encrypt 6   # encrypt day 6's private inputs into `crates/day06/data/` (key in `AOC_INPUT_KEY`)
+//! aoc decrypt     # decrypt every day's encrypted inputs into the input directory
+//! ```
+//!
+//! Defaults (log levels, input directory, default input) come from `aoc.toml`; flags override them.
+
+mod batch;
+mod encrypt;
+mod launch;
+mod registry;
+mod run_all;
+mod verify;
+mod watch;
+
+use std::{fs,
+          io::{self, IsTerminal as _},
+          path::PathBuf,
+          process::ExitCode,
+          time::Duration};
+
+use aoc_core::{CliDocs, Config, ErrKindAoc, INPUT_DIR_ENV, InputKey, InputKind, InputPath, OutputFormat, Part, Parts,
+               Result, RunReport, Variants, activate_global_default_tracing_subscriber, default_input_dir};
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use tracing::{self as tea, level_filters::LevelFilter};
+
+/// Run a day & part of Advent of Code 2024.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about, args_conflicts_with_subcommands = true)]
+pub struct Args {
+        #[command(subcommand)]
+        command:    Option<Command>,
+        /// Day to run.
+        #[arg(requires = "part", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day:        Option<u8>,
+        /// Which Part to Run (`both` runs each on a single parse)
+        part:       Option<Parts>,
+        /// Input to use.
+        input:      Option<InputKind>,
+        /// Read input from a file instead. (`-` for stdin)
+        #[arg(long = "input", value_name = "PATH", conflicts_with = "input", requires = "part")]
+        input_path: Option<InputPath>,
+        /// How to print the result.
+        #[arg(long, value_enum, default_value_t)]
+        format:     OutputFormat,
+        /// Answer the run should produce, repeated per part for `both`; exits with a distinct code on a wrong answer,
+        /// parse or solve error.
+        #[arg(long, value_name = "ANSWER", requires = "part")]
+        expect:     Vec<String>,
+        #[command(flatten)]
+        variants:   Variants,
+        /// Directory of private puzzle inputs, laid out as `dayNN/final_input.txt`.
+        /// [default: `aoc.toml`'s `input_dir`, else `data/no-sync/`]
+        #[arg(long, global = true, env = INPUT_DIR_ENV)]
+        input_dir:  Option<PathBuf>,
+        /// Set level for active logging.
+        #[arg(long, short, value_enum, global = true)]
+        log:        Option<LevelFilter>,
+        /// Set level of logs that errors will collect.
+        #[arg(long, short, value_enum, global = true)]
+        error_log:  Option<LevelFilter>,
+}
+impl Args {
+        /// Run as chosen in the launcher.
+        fn launched(self, launch: launch::Launch) -> Self {
+                let (input, input_path) = match launch.input {
+                        launch::LaunchInput::Kind(kind) => (Some(kind), None),
+                        launch::LaunchInput::File(path) => (None, Some(InputPath::File(path))),
+                };
+                Self {
+                        command: None,
+                        day: Some(launch.day),
+                        part: Some(launch.parts),
+                        input,
+                        input_path,
+                        log: Some(launch.log),
+                        ..self
+                }
+        }
+}
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+        /// Pick a day, part, input & log level from menus, then run it. (The default in a terminal.)
+        Launch,
+        /// List registered days, and any variants of their parts.
+        List {
+                /// How to print the list.
+                #[arg(long, value_enum, default_value_t)]
+                format: OutputFormat,
+        },
+        /// Run every registered day & part on example and full input, reporting answers and times.
+        All {
+                /// Seconds to allow each part before reporting it as timed out.
+                #[arg(long, short, default_value_t = 60)]
+                timeout: u64,
+                /// How to print the results.
+                #[arg(long, value_enum, default_value_t)]
+                format:  OutputFormat,
+        },
+        /// Re-run every registered day, failing if any recorded answer is not reproduced.
+        Verify {
+                /// Seconds to allow each part before reporting it as timed out.
+                #[arg(long, short, default_value_t = 60)]
+                timeout: u64,
+        },
+        /// Run a day & part on every input file in a directory, in parallel, writing a table of the results.
+        Batch {
+                /// Day to run.
+                #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+                day:     u8,
+                /// Which Part to Run
+                part:    Part,
+                /// Directory of input files. (Hidden files and subdirectories are skipped.)
+                dir:     PathBuf,
+                /// Seconds to allow each file before reporting it as timed out.
+                #[arg(long, short, default_value_t = 60)]
+                timeout: u64,
+                /// Table format.
+                #[arg(long, value_enum, default_value_t)]
+                table:   batch::TableFormat,
+                /// File to write the table to. [default: stdout]
+                #[arg(long, short)]
+                out:     Option<PathBuf>,
+        },
+        /// Rebuild & re-run a day whenever its sources or inputs change, showing what changed since the last run.
+        Watch {
+                /// Day to run.
+                #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+                day:        u8,
+                /// Which Part to Run (`both` runs each on a single parse)
+                part:       Parts,
+                /// Input to use. [default: as `aoc.toml`, else example]
+                input:      Option<InputKind>,
+                /// Read input from a file instead.
+                #[arg(long = "input", value_name = "PATH", conflicts_with = "input")]
+                input_path: Option<PathBuf>,
+                #[command(flatten)]
+                variants:   Variants,
+                /// Build the day with optimizations.
+                #[arg(long)]
+                release:    bool,
+        },
+        /// Encrypt days' private inputs into their crates' `data/`, with the team key in `AOC_INPUT_KEY`.
+        /// (Unchanged inputs are not rewritten.)
+        Encrypt {
+                /// Days to encrypt. [default: all registered days]
+                #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+                days: Vec<u8>,
+        },
+        /// Decrypt days' encrypted inputs from their crates' `data/` into the input directory.
+        Decrypt {
+                /// Days to decrypt. [default: all registered days]
+                #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+                days:  Vec<u8>,
+                /// Overwrite inputs that differ from their encrypted copies.
+                #[arg(long)]
+                force: bool,
+        },
+        /// Print a new random team key, for `AOC_INPUT_KEY`.
+        Keygen,
+        #[command(flatten)]
+        Docs(CliDocs),
+}
+
+fn main() -> Result<ExitCode> {
+        let mut cli_user_args = Args::parse();
+        let config = Config::load()?;
+        let launching = match cli_user_args.command {
+                Some(Command::Launch) => true,
+                None => cli_user_args.day.is_none() && io::stdin().is_terminal() && io::stdout().is_terminal(),
+                Some(_) => false,
+        };
+        if launching {
+                match launch::pick(config)? {
+                        Some(launch) => cli_user_args = cli_user_args.launched(launch),
+                        None => return Ok(ExitCode::SUCCESS),
+                }
+        }
+        let day_config = cli_user_args.day.map(|day| config.day(day));
+        let log_config = day_config.as_ref().map_or(config.log, |day_config| day_config.log);
+        let (log, error_log) =
+                (cli_user_args.log.or(log_config.level), cli_user_args.error_log.or(log_config.error_level));
+        // skip setting up subscriber if both passed log values are `OFF`
+        let _mb_writer_guard: Option<tracing_appender::non_blocking::WorkerGuard> = match (log, error_log) {
+                (Some(LevelFilter::OFF), Some(LevelFilter::OFF)) => None,
+                (_, _) => Some(activate_global_default_tracing_subscriber()
+                        .maybe_env_default_level(log)
+                        .maybe_trace_error_level(error_log)
+                        .call()?),
+        };
+        let _enter = tea::debug_span!("main()").entered();
+        tea::trace!(?cli_user_args);
+        let input_dir = cli_user_args.input_dir.unwrap_or_else(default_input_dir);
+
+        match (cli_user_args.command, cli_user_args.day, cli_user_args.part) {
+                (Some(Command::List { format }), _, _) => list_days(format)?,
+                (None, None, _) => list_days(OutputFormat::Text)?,
+                (Some(Command::Launch), _, _) => unreachable!("launcher choices replace the command"),
+                (Some(Command::All { timeout, format }), _, _) => {
+                        let rows = run_all::run_all(&input_dir, Duration::from_secs(timeout));
+                        match format {
+                                OutputFormat::Text => run_all::print_table(&rows),
+                                OutputFormat::Json => run_all::print_json(&rows)?,
+                        }
+                }
+                (Some(Command::Batch { day, part, dir, timeout, table, out }), _, _) => {
+                        let solution = registry::find(day).ok_or(ErrKindAoc::UnregisteredDay { day })?;
+                        let rows = batch::run_batch(solution, part, &dir, Duration::from_secs(timeout))?;
+                        match out {
+                                Some(path) => batch::write_table(&rows, table, fs::File::create(path)?)?,
+                                None => batch::write_table(&rows, table, io::stdout().lock())?,
+                        }
+                        let ok_count = rows.iter().filter(|row| row.run.outcome.is_ok()).count();
+                        eprintln!("{ok_count} of {} files OK", rows.len());
+                }
+                (Some(Command::Docs(docs)), _, _) => docs.print::<Args>()?,
+                (Some(Command::Watch { day, part, input, input_path, variants, release }), _, _) => {
+                        registry::find(day).ok_or(ErrKindAoc::UnregisteredDay { day })?;
+                        let input_path = input_path.map(std::path::absolute).transpose()?;
+                        let target = watch::Target { day, parts: part, input, input_path, variants, release };
+                        watch::watch(&target, &input_dir)?;
+                }
+                (Some(Command::Encrypt { days }), _, _) => encrypt::encrypt(&days, &input_dir)?,
+                (Some(Command::Decrypt { days, force }), _, _) => encrypt::decrypt(&days, &input_dir, force)?,
+                (Some(Command::Keygen), _, _) => println!("{}", InputKey::generate().to_hex()),
+                (Some(Command::Verify { timeout }), _, _) => {
+                        let checks = verify::verify(&input_dir, Duration::from_secs(timeout))?;
+                        verify::report(&checks)?;
+                }
+                (None, Some(day), Some(parts)) => {
+                        let solution = registry::find(day).ok_or(ErrKindAoc::UnregisteredDay { day })?;
+                        let inp = cli_user_args
+                                .input
+                                .or(day_config.and_then(|day_config| day_config.input))
+                                .unwrap_or_else(|| {
+                                        if cli_user_args.input_path.is_none() {
+                                                tea::warn!("-- No input given.  Using Example input. -- ");
+                                        }
+                                        InputKind::Example
+                                });
+                        let run = || {
+                                RunReport::run_parts(
+                                        solution,
+                                        parts,
+                                        cli_user_args.input_path.as_ref(),
+                                        inp,
+                                        &input_dir,
+                                        &cli_user_args.expect,
+                                        &cli_user_args.variants,
+                                )
+                        };
+                        let reports = match launching {
+                                true => launch::with_spinner(
+                                        format!("Day {day:02}, {}…", launch::parts_item(parts)),
+                                        run,
+                                )?,
+                                false => run()?,
+                        };
+                        tea::info!(?reports, "Process result.");
+                        return RunReport::print_all(&reports, cli_user_args.format);
+                }
+                (None, Some(_), None) => unreachable!("clap requires `part` with `day`"),
+        }
+        tea::trace!("finishing main()");
+        Ok(ExitCode::SUCCESS)
+}
+
+/// A registered day, as `aoc list --format json` prints it.
+#[derive(Debug, Serialize)]
+struct Listing {
+        day:      u8,
+        title:    &'static str,
+        /// Names of each part's variants, default first.
+        variants: [Vec<&'static str>; 2],
+}
+
+/// Print registered days and their titles, with the variants of any part that has more than one.
+fn list_days(format: OutputFormat) -> Result<()> {
+        if format == OutputFormat::Json {
+                let listings: Vec<Listing> = registry::SOLUTIONS
+                        .iter()
+                        .map(|solution| Listing {
+                                day:      solution.day(),
+                                title:    solution.title(),
+                                variants: [Part::Part1, Part::Part2].map(|part| solution.variants(part)),
+                        })
+                        .collect();
+                println!("{}", serde_json::to_string_pretty(&listings)?);
+                return Ok(());
+        }
+        for solution in registry::SOLUTIONS {
+                println!("Day {:02}: {}", solution.day(), solution.title());
+                for part in [Part::Part1, Part::Part2] {
+                        if let variants @ [_, _, ..] = solution.variants(part).as_slice() {
+                                println!("        part {part} variants: {}", variants.join(", "));
+                        }
+                }
+        }
+        Ok(())
+}
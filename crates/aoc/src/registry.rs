@@ -0,0 +1,82 @@
+//! Registry of the day solutions the `aoc` runner can dispatch to.
+
+use aoc_core::DynSolution;
+
+/// All registered days, in calendar order.
+pub static SOLUTIONS: &[&dyn DynSolution] =
+        &[&day01::Day01, &day02::Day02, &day03::Day03, &day04::Day04, &day05::Day05, &day06::Day06, &day07::Day07];
+
+/// Look up a registered day.
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+        SOLUTIONS.iter().copied().find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+        use aoc_core::{Expected, InputKind, Part, input_hash};
+        use test_log::test;
+
+        use super::*;
+
+        #[test]
+        fn days_unique_and_ordered() {
+                let days: Vec<u8> = SOLUTIONS.iter().map(|s| s.day()).collect();
+                assert!(days.windows(2).all(|w| w[0] < w[1]), "registry out of order or duplicated: {days:?}");
+                assert!(days.iter().all(|d| (1..=25).contains(d)));
+        }
+
+        #[test]
+        fn every_day_has_example_and_full_input() {
+                for solution in SOLUTIONS {
+                        for part in [Part::Part1, Part::Part2] {
+                                for kind in [InputKind::Example, InputKind::Full] {
+                                        assert!(
+                                                solution.input(kind, part).is_some(),
+                                                "day {} part {part} missing {kind} input",
+                                                solution.day()
+                                        );
+                                }
+                        }
+                }
+        }
+
+        /// Full inputs are left to `aoc verify`, as some are slow without optimization.
+        /// Example inputs must be those their answers are pinned to, if pinned.
+        #[test]
+        fn example_answers_match() -> aoc_core::Result<()> {
+                for solution in SOLUTIONS {
+                        let answers = solution.answers()?;
+                        for part in [Part::Part1, Part::Part2] {
+                                let Some(input) = solution.input(InputKind::Example, part) else {
+                                        continue;
+                                };
+                                let input = input.load()?;
+                                let expected = match answers.expected(
+                                        part,
+                                        InputKind::Example,
+                                        Some(&input_hash(&input)),
+                                ) {
+                                        Expected::Answer(expected) => expected,
+                                        Expected::Unknown => continue,
+                                        Expected::InputChanged { recorded } => panic!(
+                                                "day {} part {part}: example input is not the one its answer was recorded for ({recorded})",
+                                                solution.day()
+                                        ),
+                                };
+                                assert_eq!(
+                                        solution.solve(part, &input)?.answer,
+                                        expected,
+                                        "day {} part {part}",
+                                        solution.day()
+                                );
+                        }
+                }
+                Ok(())
+        }
+
+        #[test]
+        fn find_registered_day() {
+                assert_eq!(find(6).map(|s| s.title()), Some("Guard Gallivant"));
+                assert!(find(0).is_none());
+        }
+}
@@ -0,0 +1,199 @@
+//! `aoc all`: every registered day & part, on example and full input, as one table.
+//!
+//! Each part runs isolated (see [`run_isolated`]), so a panicking or hanging day is reported rather than ending the run.
+//! Parse & solve times are shown separately; `Total` is the wall-clock time of the isolated run.
+//! Each input is identified by the start of its [`input_hash`], so runs on different input files can be told apart.
+//! With `--format json` the rows are printed as an array of [`RunReport`]s instead.
+
+use std::{panic,
+          path::{Path, PathBuf},
+          time::Duration};
+
+use aoc_core::{DynSolution, ErrKindAoc, InputKind, IsolatedRun, Outcome, Part, Result, RunReport, Solved, input_hash,
+               run_isolated};
+use owo_colors::{OwoColorize as _, Stream::Stdout, Style};
+use tracing::{self as tea, instrument};
+
+use crate::registry;
+
+/// Longest answer/detail shown in the table before truncation.
+pub const DETAIL_WIDTH: usize = 40;
+/// Digits of an input hash shown in the table.
+pub const HASH_WIDTH: usize = 12;
+
+/// One line of the results table.
+#[derive(Debug)]
+pub struct Row {
+        pub day:           u8,
+        pub title:         &'static str,
+        pub part:          Part,
+        pub kind:          InputKind,
+        /// Hash of the input text; absent if it could not be loaded.
+        pub input_hash:    Option<String>,
+        /// Where the input was read from. (See [`DynSolution::input_origin`].)
+        pub input_source:  String,
+        /// Whether the input file is nowhere to be found. (e.g. a private full input, on a fresh clone)
+        pub input_missing: bool,
+        pub run:           IsolatedRun<Solved>,
+}
+
+/// Run every registered day, part, and (example & full) input.
+///
+/// The panic hook is silenced for the duration, as panics are reported in the table instead.
+/// A missing or unreadable input is reported as that run's error.
+#[instrument]
+pub fn run_all(input_dir: &Path, timeout: Duration) -> Vec<Row> {
+        let _silenced = silence_panics();
+        let mut rows = Vec::new();
+        for &solution in registry::SOLUTIONS {
+                for part in [Part::Part1, Part::Part2] {
+                        for kind in [InputKind::Example, InputKind::Full] {
+                                rows.push(run_one(solution, part, kind, input_dir.to_path_buf(), timeout));
+                        }
+                }
+        }
+        rows
+}
+
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Send + Sync>;
+
+/// Panic hook to restore once isolated runs are done.
+pub struct SilencedPanics(Option<PanicHook>);
+impl Drop for SilencedPanics {
+        fn drop(&mut self) {
+                if let Some(hook) = self.0.take() {
+                        panic::set_hook(hook);
+                }
+        }
+}
+
+/// Swap the panic hook for a debug log until the returned guard is dropped.
+pub fn silence_panics() -> SilencedPanics {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(|info| tea::debug!(%info, "caught panic in isolated run")));
+        SilencedPanics(Some(default_hook))
+}
+
+/// Run a single day, part & input in isolation.
+#[instrument(skip(solution), fields(day = solution.day()))]
+fn run_one(
+        solution: &'static dyn DynSolution,
+        part: Part,
+        kind: InputKind,
+        input_dir: PathBuf,
+        timeout: Duration,
+) -> Row {
+        let (day, title) = (solution.day(), solution.title());
+        let input_source = solution.input_origin(part, None, kind, &input_dir);
+        let (input_hash, input_missing, run) = match solution.load_input(kind, part, &input_dir) {
+                Ok(input) => {
+                        (Some(input_hash(&input)), false, run_isolated(timeout, move || solution.solve(part, &input)))
+                }
+                Err(err) => (None, matches!(err.source, ErrKindAoc::InputFileNotFound { .. }), IsolatedRun {
+                        outcome: Outcome::Err(err.source.to_string()),
+                        elapsed: Duration::ZERO,
+                }),
+        };
+        Row { day, title, part, kind, input_hash, input_source, input_missing, run }
+}
+
+/// Print rows as an aligned table, followed by a one line summary.
+pub fn print_table(rows: &[Row]) {
+        let title_width = rows.iter().map(|row| row.title.len()).max().unwrap_or(0);
+        println!(
+                "{:>3}  {:<title_width$}  {:>4}  {:<7}  {:<HASH_WIDTH$}  {:<7}  {:<DETAIL_WIDTH$}  {:>10}  {:>10}  {:>10}",
+                "Day", "Title", "Part", "Input", "Hash", "Status", "Answer", "Parse", "Solve", "Total"
+        );
+        for row in rows {
+                let style = match row.run.outcome {
+                        Outcome::Ok(_) => Style::new().green(),
+                        Outcome::Err(_) => Style::new().red(),
+                        Outcome::Panic(_) => Style::new().magenta(),
+                        Outcome::Timeout => Style::new().yellow(),
+                };
+                let status = format!("{:<7}", row.run.outcome.to_string())
+                        .if_supports_color(Stdout, |text| text.style(style))
+                        .to_string();
+                let (parse_time, solve_time) = match &row.run.outcome {
+                        Outcome::Ok(solved) => {
+                                (format!("{:.2?}", solved.parse_time), format!("{:.2?}", solved.solve_time))
+                        }
+                        _ => (String::new(), String::new()),
+                };
+                println!(
+                        "{:>3}  {:<title_width$}  {:>4}  {:<7}  {:<HASH_WIDTH$}  {}  {:<DETAIL_WIDTH$}  {:>10}  {:>10}  {:>10}",
+                        row.day,
+                        row.title,
+                        row.part.to_string(),
+                        row.kind.to_string(),
+                        short_hash(row.input_hash.as_deref()),
+                        status,
+                        truncate(&row.run.outcome.detail()),
+                        parse_time,
+                        solve_time,
+                        format!("{:.2?}", row.run.elapsed),
+                );
+        }
+        let ok_count = rows.iter().filter(|row| row.run.outcome.is_ok()).count();
+        println!("\n{ok_count} of {} runs OK", rows.len());
+}
+
+/// Print rows as a JSON array of [`RunReport`]s.
+pub fn print_json(rows: &[Row]) -> Result<()> {
+        let reports: Vec<RunReport> = rows
+                .iter()
+                .map(|row| {
+                        let mut report = RunReport::new(
+                                row.day,
+                                row.part,
+                                row.kind.to_string(),
+                                row.input_hash.clone(),
+                                &row.run.outcome,
+                        );
+                        report.input_source = Some(row.input_source.clone());
+                        report
+                })
+                .collect();
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+        Ok(())
+}
+
+/// Start of an input hash, as shown in tables; `-` if there is none.
+pub fn short_hash(input_hash: Option<&str>) -> &str {
+        input_hash.map_or("-", |hash| hash.get(..HASH_WIDTH).unwrap_or(hash))
+}
+
+/// First line of `detail`, cut to the table's column width.
+pub fn truncate(detail: &str) -> String {
+        let first_line = detail.lines().next().unwrap_or_default();
+        if first_line.chars().count() > DETAIL_WIDTH {
+                let mut cut: String = first_line.chars().take(DETAIL_WIDTH - 1).collect();
+                cut.push('…');
+                cut
+        } else {
+                first_line.to_string()
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        #[test]
+        fn truncate_detail() {
+                assert_eq!(truncate("5799"), "5799");
+                assert_eq!(truncate("error: bad line\n\nspantrace: ..."), "error: bad line");
+                let long = "x".repeat(DETAIL_WIDTH + 5);
+                assert_eq!(truncate(&long).chars().count(), DETAIL_WIDTH);
+        }
+
+        #[test]
+        fn short_hashes() {
+                assert_eq!(short_hash(Some(&input_hash(""))), "e3b0c44298fc");
+                assert_eq!(short_hash(Some("abc")), "abc");
+                assert_eq!(short_hash(None), "-");
+        }
+}
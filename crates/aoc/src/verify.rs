@@ -0,0 +1,181 @@
+//! `aoc verify`: re-run every registered day, checking answers against each day's `data/answers.toml`.
+//!
+//! Any recorded answer that is not reproduced (wrong answer, error, panic, or timeout) is a failure.
+//! So is an input other than the one its answer was pinned to: the input file was swapped or edited.
+//! Runs without a recorded answer are shown, but are not checked; nor are runs whose input file is missing. (e.g. the
+//! private full inputs, on a fresh clone)
+
+use std::{collections::HashMap, path::Path, time::Duration};
+
+use aoc_core::{Answers, ErrKindAoc, Expected, Outcome, Result};
+use derive_more::derive::Display;
+use owo_colors::{OwoColorize as _, Stream::Stdout, Style};
+use tracing::instrument;
+
+use crate::{registry,
+            run_all::{self, DETAIL_WIDTH, HASH_WIDTH, Row, short_hash, truncate}};
+
+/// A run, and the answer it should have produced.
+#[derive(Debug)]
+pub struct Check {
+        pub row:           Row,
+        pub expected:      Option<String>,
+        /// Hash of the input the recorded answer is pinned to, if the run's input is another.
+        pub changed_input: Option<String>,
+}
+/// Whether a run reproduced its recorded answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum Verdict {
+        #[display("PASS")]
+        Pass,
+        #[display("FAIL")]
+        Fail,
+        /// The recorded answer is for another input.
+        #[display("CHANGED")]
+        InputChanged,
+        /// No input to run on: the input file is missing.
+        #[display("MISSING")]
+        NoInput,
+        /// No recorded answer to check against.
+        #[display("-")]
+        Unchecked,
+}
+impl Check {
+        pub fn verdict(&self) -> Verdict {
+                if self.row.input_missing {
+                        return Verdict::NoInput;
+                }
+                if self.changed_input.is_some() {
+                        return Verdict::InputChanged;
+                }
+                match (&self.expected, &self.row.run.outcome) {
+                        (None, _) => Verdict::Unchecked,
+                        (Some(expected), Outcome::Ok(solved)) if solved.answer == *expected => Verdict::Pass,
+                        (Some(_), _) => Verdict::Fail,
+                }
+        }
+}
+
+/// Run every registered day, part & input, pairing each run with its recorded answer.
+#[instrument]
+pub fn verify(input_dir: &Path, timeout: Duration) -> Result<Vec<Check>> {
+        let answers: HashMap<u8, Answers> = registry::SOLUTIONS
+                .iter()
+                .map(|solution| Ok((solution.day(), solution.answers()?)))
+                .collect::<Result<_>>()?;
+        let checks = run_all::run_all(input_dir, timeout)
+                .into_iter()
+                .map(|row| {
+                        let (expected, changed_input) =
+                                match answers[&row.day].expected(row.part, row.kind, row.input_hash.as_deref()) {
+                                        Expected::Answer(answer) => (Some(answer.to_string()), None),
+                                        Expected::Unknown => (None, None),
+                                        Expected::InputChanged { recorded } => (None, Some(recorded.to_string())),
+                                };
+                        Check { row, expected, changed_input }
+                })
+                .collect();
+        Ok(checks)
+}
+
+/// Print checks as an aligned table, then a summary.
+/// Errors if any check failed, so the process exits non-zero.
+pub fn report(checks: &[Check]) -> Result<()> {
+        let title_width = checks.iter().map(|check| check.row.title.len()).max().unwrap_or(0);
+        println!(
+                "{:>3}  {:<title_width$}  {:>4}  {:<7}  {:<HASH_WIDTH$}  {:<7}  {:<DETAIL_WIDTH$}  {:<DETAIL_WIDTH$}  {:>10}",
+                "Day", "Title", "Part", "Input", "Hash", "Verdict", "Expected", "Got", "Time"
+        );
+        for check in checks {
+                let style = match check.verdict() {
+                        Verdict::Pass => Style::new().green(),
+                        Verdict::Fail => Style::new().red().bold(),
+                        Verdict::InputChanged => Style::new().yellow().bold(),
+                        Verdict::NoInput | Verdict::Unchecked => Style::new().dimmed(),
+                };
+                let verdict = format!("{:<7}", check.verdict().to_string())
+                        .if_supports_color(Stdout, |text| text.style(style))
+                        .to_string();
+                let got = match &check.row.run.outcome {
+                        Outcome::Ok(solved) => solved.answer.clone(),
+                        other => format!("{other}: {}", other.detail()),
+                };
+                let expected = match (&check.expected, &check.changed_input) {
+                        (_, Some(recorded)) => format!("(for input {})", short_hash(Some(recorded))),
+                        (Some(expected), None) => expected.clone(),
+                        (None, None) => "-".to_string(),
+                };
+                println!(
+                        "{:>3}  {:<title_width$}  {:>4}  {:<7}  {:<HASH_WIDTH$}  {}  {:<DETAIL_WIDTH$}  {:<DETAIL_WIDTH$}  {:>10}",
+                        check.row.day,
+                        check.row.title,
+                        check.row.part.to_string(),
+                        check.row.kind.to_string(),
+                        short_hash(check.row.input_hash.as_deref()),
+                        verdict,
+                        truncate(&expected),
+                        truncate(&got),
+                        format!("{:.2?}", check.row.run.elapsed),
+                );
+        }
+        let count = |verdict| checks.iter().filter(|check| check.verdict() == verdict).count();
+        let (passed, failed, changed, missing, unchecked) = (
+                count(Verdict::Pass),
+                count(Verdict::Fail),
+                count(Verdict::InputChanged),
+                count(Verdict::NoInput),
+                count(Verdict::Unchecked),
+        );
+        println!(
+                "\n{passed} passed, {failed} failed, {changed} on changed inputs, {missing} without input, {unchecked} unchecked"
+        );
+        if failed + changed > 0 {
+                Err(ErrKindAoc::VerifyFailed { failed: failed + changed })?;
+        }
+        Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+        use aoc_core::{InputKind, IsolatedRun, Part, Solved};
+        use test_log::test;
+
+        use super::*;
+
+        fn check(outcome: Outcome<Solved>, expected: Option<&str>) -> Check {
+                let run = IsolatedRun { outcome, elapsed: Duration::ZERO };
+                let row = Row {
+                        day: 1,
+                        title: "Test",
+                        part: Part::Part1,
+                        kind: InputKind::Example,
+                        input_hash: None,
+                        input_source: "embedded".to_string(),
+                        input_missing: false,
+                        run,
+                };
+                Check { row, expected: expected.map(str::to_string), changed_input: None }
+        }
+
+        fn solved(answer: &str) -> Outcome<Solved> {
+                Outcome::Ok(Solved {
+                        answer:     answer.to_string(),
+                        parse_time: Duration::ZERO,
+                        solve_time: Duration::ZERO,
+                })
+        }
+
+        #[test]
+        fn verdicts() {
+                assert_eq!(check(solved("11"), Some("11")).verdict(), Verdict::Pass);
+                assert_eq!(check(solved("12"), Some("11")).verdict(), Verdict::Fail);
+                assert_eq!(check(Outcome::Panic("todo".to_string()), Some("11")).verdict(), Verdict::Fail);
+                assert_eq!(check(Outcome::Timeout, Some("11")).verdict(), Verdict::Fail);
+                assert_eq!(check(Outcome::Panic("todo".to_string()), None).verdict(), Verdict::Unchecked);
+                let swapped = Check { changed_input: Some("aaaa".to_string()), ..check(solved("11"), None) };
+                assert_eq!(swapped.verdict(), Verdict::InputChanged);
+                let mut fresh_clone = check(Outcome::Err("Input file not found".to_string()), Some("11"));
+                fresh_clone.row.input_missing = true;
+                assert_eq!(fresh_clone.verdict(), Verdict::NoInput);
+        }
+}
@@ -0,0 +1,363 @@
+//! `aoc watch`: re-run a day & part whenever the day's sources or inputs change.
+//!
+//! The day crate is rebuilt & run with `cargo run` (so edits to its code take effect), printing `--format json`.
+//! Each run clears the screen and shows answers & timings, with what changed since the previous run.
+//! A failed build shows the compiler's output instead, and the next successful run is diffed against the last good one.
+//!
+//! Watched: the day crate's `src/` & `data/`, its directory of private inputs (if present), and any `--input` file.
+//! Hidden files & editor backups (`~`) are ignored, as are reads (e.g. by the compiler, during a build).
+
+use std::{env,
+          fmt::Write as _,
+          io::{self, Write as _},
+          path::{Path, PathBuf},
+          process::Command,
+          sync::mpsc,
+          time::Duration};
+
+use aoc_core::{INPUT_DIR_ENV, InputKind, Parts, Result, Variants, workspace_dir};
+use notify_debouncer_full::{DebounceEventResult, new_debouncer,
+                            notify::{EventKind, RecursiveMode,
+                                     event::{AccessKind, AccessMode}}};
+use owo_colors::OwoColorize as _;
+use serde::Deserialize;
+use tracing::{self as tea, instrument};
+
+/// ANSI: clear the screen and move the cursor home.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// How long the sources must be quiet before a re-run. (Editors often write a file in several steps.)
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// What to run on each change.
+#[derive(Debug, Clone)]
+pub struct Target {
+        pub day:        u8,
+        pub parts:      Parts,
+        pub input:      Option<InputKind>,
+        pub input_path: Option<PathBuf>,
+        pub variants:   Variants,
+        /// Build with `--release`.
+        pub release:    bool,
+}
+impl Target {
+        /// The day's package name. (e.g. `day06`)
+        fn package(&self) -> String {
+                format!("day{:02}", self.day)
+        }
+
+        /// Paths to watch: the day crate's `src/` & `data/`, its private inputs, and any input file named.
+        fn watched_paths(&self, input_dir: &Path) -> Vec<PathBuf> {
+                let crate_dir = workspace_dir().join("crates").join(self.package());
+                let mut paths = vec![crate_dir.join("src"), crate_dir.join("data"), input_dir.join(self.package())];
+                paths.extend(self.input_path.clone());
+                paths
+        }
+
+        /// Arguments to `cargo` to build & run the day, printing JSON.
+        fn cargo_args(&self) -> Vec<String> {
+                let mut args = vec!["run".to_string(), "--quiet".to_string()];
+                if self.release {
+                        args.push("--release".to_string());
+                }
+                args.extend(["--package".to_string(), self.package(), "--".to_string(), self.parts.to_string()]);
+                match (&self.input_path, self.input) {
+                        (Some(path), _) => args.extend(["--input".to_string(), path.display().to_string()]),
+                        (None, Some(kind)) => args.push(kind.to_string()),
+                        (None, None) => {}
+                }
+                if let Some(variant) = &self.variants.variant {
+                        args.extend(["--variant".to_string(), variant.clone()]);
+                }
+                if self.variants.all_variants {
+                        args.push("--all-variants".to_string());
+                }
+                args.extend(["--format", "json", "--log", "off"].map(str::to_string));
+                args
+        }
+
+        /// Build & run the day once.
+        #[instrument(skip(self), fields(day = self.day))]
+        fn run(&self, input_dir: &Path) -> Result<Run> {
+                let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+                let output = Command::new(cargo)
+                        .args(self.cargo_args())
+                        .current_dir(workspace_dir())
+                        .env(INPUT_DIR_ENV, input_dir)
+                        .output()?;
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                if stdout.trim().is_empty() {
+                        tea::debug!(status = %output.status, "no report printed");
+                        return Ok(Run::Failed(String::from_utf8_lossy(&output.stderr).into_owned()));
+                }
+                Ok(Run::Reports(parse_reports(&stdout)?))
+        }
+}
+
+/// The parts of a day's JSON run report shown while watching.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Snapshot {
+        pub part:       u8,
+        #[serde(default)]
+        pub variant:    Option<String>,
+        pub status:     String,
+        pub answer:     Option<String>,
+        pub parse_secs: Option<f64>,
+        pub solve_secs: Option<f64>,
+        pub error:      Option<String>,
+}
+impl Snapshot {
+        /// Part, and variant if any. (e.g. `Part 1 (hashset-loop-check)`)
+        fn label(&self) -> String {
+                match &self.variant {
+                        Some(variant) => format!("Part {} ({variant})", self.part),
+                        None => format!("Part {}", self.part),
+                }
+        }
+}
+
+/// How one build & run ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Run {
+        /// The day ran, and reported on each part.
+        Reports(Vec<Snapshot>),
+        /// Nothing was reported (e.g. the build failed); with cargo's error output.
+        Failed(String),
+}
+
+/// Reports printed with `--format json`: one object, or an array of them.
+fn parse_reports(json: &str) -> Result<Vec<Snapshot>> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        Ok(match value {
+                serde_json::Value::Array(_) => serde_json::from_value(value)?,
+                _ => vec![serde_json::from_value(value)?],
+        })
+}
+
+/// Run `target`, then again on every change to its sources or inputs, until interrupted.
+#[instrument(skip(target), fields(day = target.day))]
+pub fn watch(target: &Target, input_dir: &Path) -> Result<()> {
+        let (tx, rx) = mpsc::channel::<DebounceEventResult>();
+        let mut debouncer = new_debouncer(DEBOUNCE, None, tx).map_err(io::Error::other)?;
+        for path in target.watched_paths(input_dir).iter().filter(|path| path.exists()) {
+                tea::debug!(path = %path.display(), "watching");
+                debouncer
+                        .watch(path, RecursiveMode::Recursive)
+                        .map_err(io::Error::other)?;
+        }
+
+        let mut previous: Option<Vec<Snapshot>> = None;
+        let mut trigger = "started".to_string();
+        for count in 1.. {
+                let run = target.run(input_dir)?;
+                print!("{CLEAR_SCREEN}{}", render(target, count, &trigger, previous.as_deref(), &run));
+                io::stdout().flush()?;
+                if let Run::Reports(reports) = run {
+                        previous = Some(reports);
+                }
+                trigger = loop {
+                        match rx.recv() {
+                                Ok(Ok(events)) => {
+                                        let mut changed = events
+                                                .iter()
+                                                .filter(|event| is_change(event.kind))
+                                                .flat_map(|event| &event.paths)
+                                                .filter(|path| !ignored(path));
+                                        if let Some(path) = changed.next() {
+                                                break format!("{} changed", short_path(path));
+                                        }
+                                }
+                                Ok(Err(errs)) => {
+                                        for err in errs {
+                                                tea::warn!(%err, "watch error");
+                                        }
+                                }
+                                Err(_) => return Ok(()),
+                        }
+                };
+        }
+        Ok(())
+}
+
+/// Anything but a read: a file written, created, removed, renamed or touched.
+fn is_change(kind: EventKind) -> bool {
+        match kind {
+                EventKind::Access(AccessKind::Close(AccessMode::Write)) => true,
+                EventKind::Access(_) => false,
+                _ => true,
+        }
+}
+
+/// Hidden files & editor backups.
+fn ignored(path: &Path) -> bool {
+        path.file_name()
+                .map(|name| name.to_string_lossy())
+                .is_none_or(|name| name.starts_with('.') || name.ends_with('~'))
+}
+
+/// Path relative to the workspace, where it is within it.
+fn short_path(path: &Path) -> String {
+        path.strip_prefix(workspace_dir()).unwrap_or(path).display().to_string()
+}
+
+/// Screen for one run: a header, then each part's answer & timings, marked with what changed since `previous`.
+fn render(target: &Target, count: usize, trigger: &str, previous: Option<&[Snapshot]>, run: &Run) -> String {
+        let input = match (&target.input_path, target.input) {
+                (Some(path), _) => path.display().to_string(),
+                (None, Some(kind)) => kind.to_string(),
+                (None, None) => "default input".to_string(),
+        };
+        let mut screen = format!(
+                "{}\n{}\n\n",
+                format!("Day {:02} · part {} · {input}", target.day, target.parts).bold(),
+                format!("run {count}: {trigger}").dimmed()
+        );
+        let reports = match run {
+                Run::Reports(reports) => reports,
+                Run::Failed(stderr) => {
+                        let _ = writeln!(screen, "{}\n{stderr}", "no report (build or run failed):".red().bold());
+                        return screen;
+                }
+        };
+        for report in reports {
+                let before = previous.and_then(|previous| {
+                        previous.iter()
+                                .find(|before| (before.part, &before.variant) == (report.part, &report.variant))
+                });
+                let _ = writeln!(screen, "{}", report.label().bold());
+                match (&report.answer, before.and_then(|before| before.answer.as_ref())) {
+                        (Some(answer), Some(old)) if answer == old => {
+                                let _ = writeln!(screen, "answer: {answer} {}", "(unchanged)".dimmed());
+                        }
+                        (Some(answer), Some(old)) => {
+                                let _ = writeln!(screen, "{}", "answer changed:".yellow().bold());
+                                let _ = writeln!(screen, "{}", format!("- {old}").red());
+                                let _ = writeln!(screen, "{}", format!("+ {answer}").green());
+                        }
+                        (Some(answer), None) => {
+                                let _ = writeln!(screen, "answer: {answer}");
+                        }
+                        (None, old) => {
+                                let error = report.error.as_deref().unwrap_or_default();
+                                let _ = writeln!(screen, "{} {error}", format!("{}:", report.status).red().bold());
+                                if let Some(old) = old {
+                                        let _ = writeln!(screen, "{}", format!("(previously answered {old})").dimmed());
+                                }
+                        }
+                }
+                if let (Some(parse), Some(solve)) = (report.parse_secs, report.solve_secs) {
+                        let _ = write!(screen, "Parse: {:.2?}  Solve: {:.2?}", secs(parse), secs(solve));
+                        if let Some(old_solve) = before.and_then(|before| before.solve_secs) {
+                                let change = match solve >= old_solve {
+                                        true => format!("+{:.2?}", secs(solve - old_solve)),
+                                        false => format!("-{:.2?}", secs(old_solve - solve)),
+                                };
+                                let _ = write!(screen, "  {}", format!("(solve {change} on last run)").dimmed());
+                        }
+                        screen.push('\n');
+                }
+                screen.push('\n');
+        }
+        screen
+}
+
+fn secs(secs: f64) -> Duration {
+        Duration::from_secs_f64(secs)
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        fn target() -> Target {
+                Target {
+                        day:        6,
+                        parts:      Parts::Part2,
+                        input:      Some(InputKind::Example),
+                        input_path: None,
+                        variants:   Variants::default(),
+                        release:    false,
+                }
+        }
+
+        fn snapshot(answer: Option<&str>, solve_secs: f64) -> Snapshot {
+                Snapshot {
+                        part:       2,
+                        variant:    None,
+                        status:     if answer.is_some() { "ok" } else { "err" }.to_string(),
+                        answer:     answer.map(str::to_string),
+                        parse_secs: Some(0.001),
+                        solve_secs: answer.map(|_| solve_secs),
+                        error:      answer.is_none().then(|| "no guard found".to_string()),
+                }
+        }
+
+        #[test]
+        fn cargo_args_for_target() {
+                let args = target().cargo_args();
+                assert_eq!(args.join(" "), "run --quiet --package day06 -- 2 example --format json --log off");
+                let target = Target {
+                        input_path: Some(PathBuf::from("/tmp/in.txt")),
+                        variants: Variants { variant: None, all_variants: true },
+                        release: true,
+                        ..target()
+                };
+                assert_eq!(
+                        target.cargo_args().join(" "),
+                        "run --quiet --release --package day06 -- 2 --input /tmp/in.txt --all-variants --format json \
+                         --log off"
+                );
+        }
+
+        #[test]
+        fn reports_from_json() -> Result<()> {
+                let one = r#"{"day": 6, "part": 2, "status": "ok", "answer": "6", "parse_secs": 0.1, "solve_secs": 0.2,
+                              "error": null, "exit_code": 0}"#;
+                assert_eq!(parse_reports(one)?.len(), 1);
+                let many = format!("[{one}, {one}]");
+                assert_eq!(parse_reports(&many)?.len(), 2);
+                assert!(parse_reports("error: could not compile").is_err());
+                Ok(())
+        }
+
+        #[test]
+        fn render_diffs_against_previous() {
+                let target = target();
+                let first = render(&target, 1, "started", None, &Run::Reports(vec![snapshot(Some("6"), 0.002)]));
+                assert!(first.contains("answer: 6\n"), "{first}");
+
+                let previous = [snapshot(Some("6"), 0.002)];
+                let same = render(&target, 2, "x", Some(&previous), &Run::Reports(vec![snapshot(Some("6"), 0.003)]));
+                assert!(same.contains("(unchanged)") && same.contains("(solve +1.00ms on last run)"), "{same}");
+
+                let changed = render(&target, 3, "x", Some(&previous), &Run::Reports(vec![snapshot(Some("7"), 0.001)]));
+                assert!(changed.contains("- 6") && changed.contains("+ 7"), "{changed}");
+
+                let failed = render(&target, 4, "x", Some(&previous), &Run::Reports(vec![snapshot(None, 0.0)]));
+                assert!(failed.contains("no guard found") && failed.contains("(previously answered 6)"), "{failed}");
+
+                let build = render(&target, 5, "x", Some(&previous), &Run::Failed("error[E0308]".to_string()));
+                assert!(build.contains("error[E0308]"), "{build}");
+        }
+
+        #[test]
+        fn ignores_reads() {
+                use notify_debouncer_full::notify::event::{CreateKind, ModifyKind};
+
+                assert!(!is_change(EventKind::Access(AccessKind::Open(AccessMode::Read))));
+                assert!(!is_change(EventKind::Access(AccessKind::Close(AccessMode::Read))));
+                assert!(is_change(EventKind::Access(AccessKind::Close(AccessMode::Write))));
+                assert!(is_change(EventKind::Modify(ModifyKind::Any)));
+                assert!(is_change(EventKind::Create(CreateKind::File)));
+        }
+
+        #[test]
+        fn ignores_hidden_and_backup_files() {
+                assert!(ignored(Path::new("src/.part2_lib.rs.swp")));
+                assert!(ignored(Path::new("src/part2_lib.rs~")));
+                assert!(!ignored(Path::new("src/part2_lib.rs")));
+        }
+}
@@ -0,0 +1,189 @@
+//! Known-good answers, kept per day in `data/answers.toml`.
+//!
+//! ```toml
+//! [part1]
+//! example = "41"
+//! full = { answer = "4711", input = "1821c55b…" }
+//!
+//! [part2]
+//! example = "6"
+//!
+//! [part2.by_input]
+//! "9f86d081…" = "1562"
+//! ```
+//!
+//! Answers are strings, compared against the rendered answer; a missing entry means "not yet known".
+//! An answer may be pinned to the [`input_hash`](crate::input_hash) of the input it was recorded for, so a swapped
+//! input file shows up as such rather than as a wrong answer. Answers for other inputs (e.g. a teammate's) are kept
+//! `by_input`, keyed by input hash.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::{InputKind, Part};
+
+/// Known answers for a day, by part.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+        #[serde(default)]
+        pub part1: PartAnswers,
+        #[serde(default)]
+        pub part2: PartAnswers,
+}
+/// Known answers for a part, by input kind, and by input hash.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartAnswers {
+        pub example:  Option<RecordedAnswer>,
+        pub full:     Option<RecordedAnswer>,
+        pub custom:   Option<RecordedAnswer>,
+        /// Answers by [`input_hash`](crate::input_hash), whatever the input's kind.
+        #[serde(default)]
+        pub by_input: BTreeMap<String, String>,
+}
+/// An answer, optionally pinned to the input it was recorded for.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum RecordedAnswer {
+        Answer(String),
+        Pinned(PinnedAnswer),
+}
+/// An answer, with the [`input_hash`](crate::input_hash) of the input it was recorded for.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PinnedAnswer {
+        pub answer: String,
+        pub input:  String,
+}
+impl RecordedAnswer {
+        pub fn answer(&self) -> &str {
+                match self {
+                        RecordedAnswer::Answer(answer) | RecordedAnswer::Pinned(PinnedAnswer { answer, .. }) => answer,
+                }
+        }
+
+        /// Hash of the input the answer is for, if pinned.
+        pub fn input(&self) -> Option<&str> {
+                match self {
+                        RecordedAnswer::Answer(_) => None,
+                        RecordedAnswer::Pinned(PinnedAnswer { input, .. }) => Some(input),
+                }
+        }
+}
+
+/// Answer a run should produce, given what is recorded for its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected<'a> {
+        Answer(&'a str),
+        /// Nothing recorded for the input.
+        Unknown,
+        /// The recorded answer is for another input: the input file was swapped (or edited).
+        InputChanged {
+                recorded: &'a str,
+        },
+}
+impl<'a> Expected<'a> {
+        /// The answer, if known.
+        pub fn answer(self) -> Option<&'a str> {
+                match self {
+                        Expected::Answer(answer) => Some(answer),
+                        Expected::Unknown | Expected::InputChanged { .. } => None,
+                }
+        }
+}
+
+impl Answers {
+        /// Parse an answers file.
+        pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+                toml::from_str(text)
+        }
+
+        fn part(&self, part: Part) -> &PartAnswers {
+                match part {
+                        Part::Part1 => &self.part1,
+                        Part::Part2 => &self.part2,
+                }
+        }
+
+        /// Known answer for a part & input kind, if recorded. (Whatever input it was recorded for.)
+        pub fn get(&self, part: Part, kind: InputKind) -> Option<&str> {
+                self.recorded(part, kind).map(RecordedAnswer::answer)
+        }
+
+        fn recorded(&self, part: Part, kind: InputKind) -> Option<&RecordedAnswer> {
+                let part_answers = self.part(part);
+                match kind {
+                        InputKind::Example => part_answers.example.as_ref(),
+                        InputKind::Full => part_answers.full.as_ref(),
+                        InputKind::Custom => part_answers.custom.as_ref(),
+                }
+        }
+
+        /// Answer expected from a part on an input of the given kind & [`input_hash`](crate::input_hash).
+        ///
+        /// An answer recorded `by_input` for the hash wins; else the kind's answer, unless pinned to another input.
+        pub fn expected(&self, part: Part, kind: InputKind, input_hash: Option<&str>) -> Expected<'_> {
+                if let Some(answer) = input_hash.and_then(|hash| self.part(part).by_input.get(hash)) {
+                        return Expected::Answer(answer);
+                }
+                match self.recorded(part, kind) {
+                        None => Expected::Unknown,
+                        Some(recorded) => match (recorded.input(), input_hash) {
+                                (Some(pinned), Some(hash)) if pinned != hash => {
+                                        Expected::InputChanged { recorded: pinned }
+                                }
+                                _ => Expected::Answer(recorded.answer()),
+                        },
+                }
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        #[test]
+        fn parse_partial_answers() -> Result<(), toml::de::Error> {
+                let answers = Answers::from_toml("[part1]\nexample = \"41\"\nfull = \"4711\"\n")?;
+                assert_eq!(answers.get(Part::Part1, InputKind::Example), Some("41"));
+                assert_eq!(answers.get(Part::Part1, InputKind::Full), Some("4711"));
+                assert_eq!(answers.get(Part::Part1, InputKind::Custom), None);
+                assert_eq!(answers.get(Part::Part2, InputKind::Example), None);
+                Ok(())
+        }
+
+        #[test]
+        fn reject_unknown_keys() {
+                assert!(Answers::from_toml("[part1]\nfinal = \"4711\"\n").is_err());
+                assert!(Answers::from_toml("[part3]\n").is_err());
+                assert!(Answers::from_toml("[part1]\nfull = { answer = \"4711\", hash = \"abc\" }\n").is_err());
+        }
+
+        #[test]
+        fn expected_by_input_hash() -> Result<(), toml::de::Error> {
+                let answers = Answers::from_toml(indoc::indoc! {r#"
+                        [part1]
+                        example = "41"
+                        full = { answer = "4711", input = "aaaa" }
+
+                        [part1.by_input]
+                        "bbbb" = "5120"
+                "#})?;
+                let expected = |kind, hash| answers.expected(Part::Part1, kind, hash);
+                assert_eq!(answers.get(Part::Part1, InputKind::Full), Some("4711"));
+                assert_eq!(expected(InputKind::Full, Some("aaaa")), Expected::Answer("4711"));
+                assert_eq!(expected(InputKind::Full, None), Expected::Answer("4711"));
+                assert_eq!(expected(InputKind::Full, Some("bbbb")), Expected::Answer("5120"));
+                assert_eq!(expected(InputKind::Full, Some("cccc")), Expected::InputChanged { recorded: "aaaa" });
+                // unpinned answers hold for any input of their kind
+                assert_eq!(expected(InputKind::Example, Some("cccc")), Expected::Answer("41"));
+                assert_eq!(expected(InputKind::Custom, Some("bbbb")), Expected::Answer("5120"));
+                assert_eq!(expected(InputKind::Custom, Some("cccc")), Expected::Unknown);
+                Ok(())
+        }
+}
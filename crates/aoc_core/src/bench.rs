@@ -0,0 +1,88 @@
+//! Inputs for each day's `divan` benchmarks (`crates/dayNN/benches/solutions.rs`), as benchmark arguments.
+
+use std::{borrow::Cow, fmt};
+
+use crate::{InputKind, Part, Solution};
+
+/// An input to benchmark on; shown by `divan` as its kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchInput {
+        pub kind: InputKind,
+        pub text: Cow<'static, str>,
+}
+impl fmt::Display for BenchInput {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.kind.fmt(f)
+        }
+}
+
+/// A day's example & full inputs for a part, as far as they can be loaded.
+///
+/// The full input is read from the input directory (unless embedded); where it is missing, the part is only
+/// benchmarked on its example.
+pub fn bench_inputs<S: Solution>(part: Part) -> Vec<BenchInput> {
+        [InputKind::Example, InputKind::Full]
+                .into_iter()
+                .filter_map(|kind| {
+                        let source = S::input(kind, part)?;
+                        match source.load() {
+                                Ok(text) => Some(BenchInput { kind, text }),
+                                Err(err) => {
+                                        eprintln!(
+                                                "Day{:02} part {part} is not benchmarked on its {kind} input: {err}",
+                                                S::DAY
+                                        );
+                                        None
+                                }
+                        }
+                })
+                .collect()
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+        use crate::{DynError, InputSource};
+
+        /// Has an example, and a full input that is nowhere to be found.
+        struct Stub;
+        impl Solution for Stub {
+                type Answer = u64;
+                type Error = DynError;
+                type Parsed = ();
+
+                const ANSWERS: &'static str = "";
+                const DAY: u8 = 25;
+                const TITLE: &'static str = "Stub";
+
+                fn input(kind: InputKind, _part: Part) -> Option<InputSource> {
+                        match kind {
+                                InputKind::Example => Some(InputSource::Embedded("1 2 3\n")),
+                                InputKind::Full => Some(InputSource::File { day: 25, name: "no_such_input.txt" }),
+                                InputKind::Custom => None,
+                        }
+                }
+
+                fn parse(_input: &str) -> std::result::Result<Self::Parsed, Self::Error> {
+                        Ok(())
+                }
+
+                fn part1(_parsed: &Self::Parsed) -> std::result::Result<Self::Answer, Self::Error> {
+                        Ok(0)
+                }
+
+                fn part2(_parsed: &Self::Parsed) -> std::result::Result<Self::Answer, Self::Error> {
+                        Ok(0)
+                }
+        }
+
+        #[test]
+        fn missing_full_input_skipped() {
+                let inputs = bench_inputs::<Stub>(Part::Part1);
+                assert_eq!(inputs, [BenchInput { kind: InputKind::Example, text: Cow::Borrowed("1 2 3\n") }]);
+                assert_eq!(inputs[0].to_string(), "example");
+        }
+}
@@ -0,0 +1,150 @@
+//! Shell completions & man pages for the workspace's CLIs, generated from their own clap definitions.
+//!
+//! Every CLI carries [`CliDocs`] as hidden subcommands, so completions cover each day's aliases (`i`, `II`, `one`, ...).
+//!
+//! ```zsh
+//! day06 completions zsh > ~/.zfunc/_day06
+//! day06 man > day06.1
+//! ```
+
+use std::io::{self, Write as _};
+
+use clap::{Command, CommandFactory, Subcommand,
+           builder::{PossibleValue, PossibleValuesParser}};
+use clap_complete::Shell;
+use tracing::instrument;
+
+use crate::Result;
+
+/// Hidden subcommands to print a CLI's shell completions or man page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum CliDocs {
+        /// Print shell completions.
+        #[command(hide = true)]
+        Completions {
+                /// Shell to generate completions for.
+                #[arg(value_enum)]
+                shell: Shell,
+        },
+        /// Print a man page (roff).
+        #[command(hide = true)]
+        Man,
+}
+impl CliDocs {
+        /// Completions or man page for the CLI `C`.
+        #[instrument]
+        pub fn render<C: CommandFactory>(self) -> Result<Vec<u8>> {
+                let mut cmd = C::command();
+                let mut out = Vec::new();
+                match self {
+                        Self::Completions { shell } => {
+                                cmd = complete_aliases(cmd);
+                                let bin_name = cmd.get_name().to_string();
+                                clap_complete::generate(shell, &mut cmd, bin_name, &mut out);
+                        }
+                        Self::Man => clap_mangen::Man::new(cmd).render(&mut out)?,
+                }
+                Ok(out)
+        }
+
+        /// Write completions or man page for the CLI `C`.
+        pub fn write<C: CommandFactory>(self, writer: &mut impl io::Write) -> Result<()> {
+                writer.write_all(&self.render::<C>()?)?;
+                Ok(())
+        }
+
+        /// Print completions or man page for the CLI `C` to stdout.
+        ///
+        /// A reader that stops early (e.g. `| head`) is not an error.
+        pub fn print<C: CommandFactory>(self) -> Result<()> {
+                let rendered = self.render::<C>()?;
+                let mut stdout = io::stdout().lock();
+                match stdout.write_all(&rendered).and_then(|()| stdout.flush()) {
+                        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+                        written => Ok(written?),
+                }
+        }
+}
+
+/// Offer value aliases (e.g. `II` for `part2`) as completions too. (Generators otherwise complete only canonical names.)
+fn complete_aliases(cmd: Command) -> Command {
+        let cmd = cmd.mut_args(|arg| {
+                let values = arg.get_possible_values();
+                if values.iter().all(|value| value.get_name_and_aliases().count() == 1) {
+                        return arg;
+                }
+                let values: Vec<PossibleValue> = values
+                        .iter()
+                        .filter(|value| !value.is_hide_set())
+                        .flat_map(|value| {
+                                value.get_name_and_aliases().map(|name| {
+                                        let alias = PossibleValue::new(name.to_string());
+                                        match value.get_help() {
+                                                Some(help) => alias.help(help.clone()),
+                                                None => alias,
+                                        }
+                                })
+                        })
+                        .collect();
+                arg.value_parser(PossibleValuesParser::new(values))
+        });
+        let subcommands: Vec<_> = cmd.get_subcommands().map(|sub| sub.get_name().to_string()).collect();
+        subcommands
+                .into_iter()
+                .fold(cmd, |cmd, name| cmd.mut_subcommand(name, complete_aliases))
+}
+
+#[cfg(test)]
+mod tests {
+        use clap::{Parser, ValueEnum as _};
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+        use crate::Parts;
+
+        #[derive(Debug, Parser)]
+        #[command(name = "dayxx", subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
+        struct Args {
+                #[command(subcommand)]
+                docs: Option<CliDocs>,
+                #[arg(required = true)]
+                part: Option<Parts>,
+        }
+
+        #[test]
+        fn parse_docs_subcommand_without_part() {
+                let args = Args::parse_from(["dayxx", "completions", "zsh"]);
+                assert_eq!(args.docs, Some(CliDocs::Completions { shell: Shell::Zsh }));
+                let args = Args::parse_from(["dayxx", "man"]);
+                assert_eq!(args.docs, Some(CliDocs::Man));
+                let args = Args::parse_from(["dayxx", "II"]);
+                assert_eq!((args.docs, args.part), (None, Some(Parts::Part2)));
+                assert!(Args::try_parse_from(["dayxx"]).is_err());
+        }
+
+        #[test]
+        fn completions_include_aliases() -> Result<()> {
+                for shell in Shell::value_variants() {
+                        let mut out = Vec::new();
+                        CliDocs::Completions { shell: *shell }.write::<Args>(&mut out)?;
+                        let out = String::from_utf8(out).expect("completions are utf-8");
+                        assert!(out.contains("dayxx"), "{shell}");
+                }
+                let mut out = Vec::new();
+                CliDocs::Completions { shell: Shell::Zsh }.write::<Args>(&mut out)?;
+                let out = String::from_utf8(out).expect("completions are utf-8");
+                assert!(out.contains("one") && out.contains("II"), "{out}");
+                Ok(())
+        }
+
+        #[test]
+        fn man_page() -> Result<()> {
+                let mut out = Vec::new();
+                CliDocs::Man.write::<Args>(&mut out)?;
+                let out = String::from_utf8(out).expect("man page is utf-8");
+                assert!(out.starts_with(".ie"), "{out}");
+                assert!(out.contains(".TH dayxx"));
+                Ok(())
+        }
+}
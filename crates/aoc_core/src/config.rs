@@ -0,0 +1,222 @@
+//! Project config: defaults for every binary, from one TOML file. Command-line flags override it.
+//!
+//! Read from the path in [`CONFIG_ENV`] if set, else the workspace's `aoc.toml` (which may be absent).
+//!
+//! ```toml
+//! input_dir = "data/no-sync"  # relative to this file; `--input-dir` & `AOC_INPUT_DIR` override
+//! input = "full"              # input to use when none is named
+//!
+//! [log]
+//! level = "warn"              # `--log`;       `RUST_LOG` directives still apply
+//! error_level = "trace"       # `--error-log`
+//!
+//! [day.6]                     # overrides the above, for day 6 only
+//! input = "example"
+//! log = { level = "info" }
+//! options = { animate = true } # day-specific knobs, read with `DayConfig::option`
+//! ```
+
+use std::{collections::BTreeMap,
+          env, fs,
+          path::{Path, PathBuf},
+          str::FromStr,
+          sync::OnceLock};
+
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
+use tracing::{self as tea, instrument, level_filters::LevelFilter};
+
+use crate::{InputKind, Result, support::ErrKindAoc, workspace_dir};
+
+/// Environment variable naming the config file to use instead of the workspace's `aoc.toml`.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+static LOADED: OnceLock<Config> = OnceLock::new();
+
+/// Project config, as read from `aoc.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+        /// Directory of private puzzle inputs. (Made absolute on load, relative to the config file.)
+        pub input_dir: Option<PathBuf>,
+        /// Input to use when none is named on the command line.
+        pub input:     Option<InputKind>,
+        pub log:       LogConfig,
+        /// Per-day settings, by day number: `[day.6]`.
+        #[serde(deserialize_with = "day_tables")]
+        pub day:       BTreeMap<u8, DayConfig>,
+}
+
+/// Default log levels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+        /// Level for active logging. (`--log`)
+        #[serde(deserialize_with = "level_filter")]
+        pub level:       Option<LevelFilter>,
+        /// Level of logs that errors will collect. (`--error-log`)
+        #[serde(deserialize_with = "level_filter")]
+        pub error_level: Option<LevelFilter>,
+}
+
+/// Settings for a single day.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DayConfig {
+        /// Input to use when none is named on the command line.
+        pub input:   Option<InputKind>,
+        pub log:     LogConfig,
+        /// Day-specific knobs, free-form.
+        pub options: toml::Table,
+}
+impl DayConfig {
+        /// Value of the day-specific knob `key`, if set.
+        pub fn option<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+                self.options
+                        .get(key)
+                        .map(|value| value.clone().try_into::<T>())
+                        .transpose()
+                        .map_err(|source| {
+                                ErrKindAoc::ConfigOption { key: key.to_string(), source: Box::new(source) }.into()
+                        })
+        }
+}
+
+impl Config {
+        /// The project config, read once per process. (An absent workspace `aoc.toml` is an empty config.)
+        #[instrument]
+        pub fn load() -> Result<&'static Self> {
+                if let Some(config) = LOADED.get() {
+                        return Ok(config);
+                }
+                let config = match env::var_os(CONFIG_ENV) {
+                        Some(path) => Self::read(Path::new(&path))?,
+                        None if !workspace_config_path().exists() => {
+                                tea::debug!("no workspace config file; using defaults");
+                                Self::default()
+                        }
+                        None => Self::read(&workspace_config_path())?,
+                };
+                Ok(LOADED.get_or_init(|| config))
+        }
+
+        /// Read config from the file at `path`.
+        pub fn read(path: &Path) -> Result<Self> {
+                let text = fs::read_to_string(path)
+                        .map_err(|source| ErrKindAoc::ConfigRead { path: path.to_path_buf(), source })?;
+                Self::parse(&text, path.parent().unwrap_or(Path::new("."))).map_err(|source| {
+                        ErrKindAoc::Config { path: path.to_path_buf(), source: Box::new(source) }.into()
+                })
+        }
+
+        /// Parse config text, resolving a relative `input_dir` against `base_dir`.
+        pub fn parse(text: &str, base_dir: &Path) -> std::result::Result<Self, toml::de::Error> {
+                let mut config: Self = toml::from_str(text)?;
+                config.input_dir = config.input_dir.map(|dir| base_dir.join(dir));
+                Ok(config)
+        }
+
+        /// Settings for `day`: its own `[day.N]` table, falling back to the top-level settings.
+        pub fn day(&self, day: u8) -> DayConfig {
+                let own = self.day.get(&day).cloned().unwrap_or_default();
+                DayConfig {
+                        input:   own.input.or(self.input),
+                        log:     LogConfig {
+                                level:       own.log.level.or(self.log.level),
+                                error_level: own.log.error_level.or(self.log.error_level),
+                        },
+                        options: own.options,
+                }
+        }
+}
+
+/// The workspace's `aoc.toml`, as located at compile time.
+fn workspace_config_path() -> PathBuf {
+        workspace_dir().join("aoc.toml")
+}
+
+fn level_filter<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<LevelFilter>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+                .map(|level| LevelFilter::from_str(&level).map_err(serde::de::Error::custom))
+                .transpose()
+}
+
+/// `[day.N]` tables: TOML keys are strings, so parse the day numbers out of them.
+fn day_tables<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<BTreeMap<u8, DayConfig>, D::Error> {
+        BTreeMap::<String, DayConfig>::deserialize(deserializer)?
+                .into_iter()
+                .map(|(key, config)| match key.parse::<u8>() {
+                        Ok(day @ 1..=25) => Ok((day, config)),
+                        _ => Err(serde::de::Error::custom(format!(
+                                "`[day.{key}]` is not a day of the advent (1..=25)"
+                        ))),
+                })
+                .collect()
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        const EXAMPLE: &str = indoc! {r#"
+                input_dir = "inputs"
+                input = "full"
+
+                [log]
+                level = "warn"
+
+                [day.6]
+                input = "example"
+                log = { level = "debug", error_level = "off" }
+                options = { animate = true }
+
+                [day.07]
+                options = { depth = 3 }
+        "#};
+
+        #[test]
+        fn parse_and_resolve_days() -> Result<()> {
+                let config = Config::parse(EXAMPLE, Path::new("/base")).expect("example config parses");
+                assert_eq!(config.input_dir, Some(PathBuf::from("/base/inputs")));
+
+                let day6 = config.day(6);
+                assert_eq!(day6.input, Some(InputKind::Example));
+                assert_eq!(day6.log, LogConfig {
+                        level:       Some(LevelFilter::DEBUG),
+                        error_level: Some(LevelFilter::OFF),
+                });
+                assert_eq!(day6.option::<bool>("animate")?, Some(true));
+                assert_eq!(day6.option::<bool>("missing")?, None);
+                assert!(day6.option::<u32>("animate").is_err());
+
+                let day7 = config.day(7);
+                assert_eq!(day7.input, Some(InputKind::Full));
+                assert_eq!(day7.log, LogConfig { level: Some(LevelFilter::WARN), error_level: None });
+                assert_eq!(day7.option::<u32>("depth")?, Some(3));
+
+                assert_eq!(config.day(1).log.level, Some(LevelFilter::WARN));
+                Ok(())
+        }
+
+        #[test]
+        fn reject_bad_config() {
+                for text in [
+                        "inptu = \"full\"",
+                        "input = \"everything\"",
+                        "[log]\nlevel = \"loud\"",
+                        "[day.26]\ninput = \"full\"",
+                        "[day.six]\ninput = \"full\"",
+                ] {
+                        assert!(Config::parse(text, Path::new("")).is_err(), "{text}");
+                }
+        }
+
+        #[test]
+        fn workspace_config_parses() -> Result<()> {
+                Config::read(&workspace_config_path())?;
+                Ok(())
+        }
+}
@@ -0,0 +1,266 @@
+//! Encrypted-at-rest puzzle inputs, so private inputs can be committed & shared with a team key.
+//!
+//! A private input `<input dir>/dayNN/<name>` is stored encrypted as `crates/dayNN/data/<name>.enc`
+//! (XChaCha20-Poly1305, keyed by [`INPUT_KEY_ENV`]). When the plain file is absent, the input loader decrypts the
+//! stored copy instead. `aoc encrypt` / `aoc decrypt` convert a day's inputs between the two.
+//!
+//! Stored file layout: [`MAGIC`], a random 24 byte nonce, then the ciphertext & tag.
+//! The day & file name are bound in as associated data, so a stored input cannot be passed off as another.
+
+use std::{fmt::{self, Write as _},
+          fs,
+          path::{Path, PathBuf}};
+
+use chacha20poly1305::{AeadCore as _, KeyInit as _, XChaCha20Poly1305, XNonce,
+                       aead::{Aead as _, OsRng, Payload}};
+use tracing::{self as tea, instrument};
+
+use crate::{Result, support::ErrKindAoc, workspace_dir};
+
+/// Environment variable holding the team key: 64 hex digits.
+pub const INPUT_KEY_ENV: &str = "AOC_INPUT_KEY";
+/// Extension added to the name of a stored (encrypted) input.
+pub const ENCRYPTED_EXT: &str = "enc";
+/// First bytes of every stored input, naming the format.
+const MAGIC: &[u8; 8] = b"aocenc01";
+const NONCE_LEN: usize = 24;
+
+/// Key that stored inputs are encrypted with.
+#[derive(Clone)]
+pub struct InputKey(chacha20poly1305::Key);
+impl fmt::Debug for InputKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("InputKey(..)")
+        }
+}
+impl InputKey {
+        /// A new random key.
+        pub fn generate() -> Self {
+                Self(XChaCha20Poly1305::generate_key(&mut OsRng))
+        }
+
+        /// The key in [`INPUT_KEY_ENV`], if set (and not empty).
+        pub fn from_env() -> Result<Option<Self>> {
+                std::env::var(INPUT_KEY_ENV)
+                        .ok()
+                        .filter(|hex| !hex.trim().is_empty())
+                        .map(|hex| Self::from_hex(&hex))
+                        .transpose()
+        }
+
+        /// Key from its 64 hex digits.
+        pub fn from_hex(hex: &str) -> Result<Self> {
+                let hex = hex.trim();
+                let bytes: Option<Vec<u8>> = match hex.len() == 64 && hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                        true => (0..hex.len())
+                                .step_by(2)
+                                .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).ok())
+                                .collect(),
+                        false => None,
+                };
+                let bytes = bytes.ok_or(ErrKindAoc::InvalidInputKey)?;
+                Ok(Self(*chacha20poly1305::Key::from_slice(&bytes)))
+        }
+
+        /// The key as 64 hex digits.
+        pub fn to_hex(&self) -> String {
+                self.0.iter().fold(String::with_capacity(64), |mut hex, byte| {
+                        let _ = write!(hex, "{byte:02x}");
+                        hex
+                })
+        }
+
+        /// Encrypt an input, for storing as `name` of `day`.
+        pub fn encrypt(&self, day: u8, name: &str, plain: &[u8]) -> Vec<u8> {
+                let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+                let aad = associated_data(day, name);
+                let sealed = XChaCha20Poly1305::new(&self.0)
+                        .encrypt(&nonce, Payload { msg: plain, aad: aad.as_bytes() })
+                        .expect("encrypting to memory cannot fail");
+                [MAGIC.as_slice(), nonce.as_slice(), &sealed].concat()
+        }
+
+        /// Decrypt a stored input; `None` if it is not one, was stored under another name, or the key is wrong.
+        pub fn decrypt(&self, day: u8, name: &str, stored: &[u8]) -> Option<Vec<u8>> {
+                let rest = stored.strip_prefix(MAGIC.as_slice())?;
+                if rest.len() < NONCE_LEN {
+                        return None;
+                }
+                let (nonce, sealed) = rest.split_at(NONCE_LEN);
+                let aad = associated_data(day, name);
+                XChaCha20Poly1305::new(&self.0)
+                        .decrypt(XNonce::from_slice(nonce), Payload { msg: sealed, aad: aad.as_bytes() })
+                        .ok()
+        }
+}
+
+fn associated_data(day: u8, name: &str) -> String {
+        format!("day{day:02}/{name}")
+}
+
+/// Where a day's stored inputs live: the day crate's `data/` directory.
+pub fn stored_input_dir(day: u8) -> PathBuf {
+        workspace_dir().join("crates").join(format!("day{day:02}")).join("data")
+}
+
+/// Where the stored copy of a day's input `name` would be.
+pub(crate) fn stored_input_path(day: u8, name: &str) -> PathBuf {
+        stored_input_dir(day).join(format!("{name}.{ENCRYPTED_EXT}"))
+}
+
+/// Text of the stored copy of a day's input `name`, if there is one. (Needs the key in [`INPUT_KEY_ENV`].)
+#[instrument]
+pub fn load_stored_input(day: u8, name: &str) -> Result<Option<String>> {
+        let path = stored_input_path(day, name);
+        if !path.is_file() {
+                return Ok(None);
+        }
+        tea::debug!(?path, "decrypting stored input");
+        let key = InputKey::from_env()?.ok_or_else(|| ErrKindAoc::MissingInputKey { path: path.clone() })?;
+        let stored = fs::read(&path).map_err(|source| ErrKindAoc::InputFileRead { path: path.clone(), source })?;
+        let plain = key
+                .decrypt(day, name, &stored)
+                .ok_or_else(|| ErrKindAoc::Decrypt { path: path.clone() })?;
+        Ok(Some(String::from_utf8(plain).map_err(|_| ErrKindAoc::Decrypt { path })?))
+}
+
+/// Encrypt each plain input in `plain_dir` into `stored_dir`, returning the files written.
+///
+/// Stored inputs that already decrypt to the same text are left alone, so re-running changes nothing.
+#[instrument(skip(key))]
+pub fn encrypt_inputs(key: &InputKey, day: u8, plain_dir: &Path, stored_dir: &Path) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        for name in file_names(plain_dir)?
+                .into_iter()
+                .filter(|name| Path::new(name).extension() != Some(ENCRYPTED_EXT.as_ref()))
+        {
+                let plain = read(&plain_dir.join(&name))?;
+                let path = stored_dir.join(format!("{name}.{ENCRYPTED_EXT}"));
+                let unchanged = fs::read(&path)
+                        .ok()
+                        .and_then(|stored| key.decrypt(day, &name, &stored))
+                        .is_some_and(|stored| stored == plain);
+                if !unchanged {
+                        fs::create_dir_all(stored_dir)?;
+                        fs::write(&path, key.encrypt(day, &name, &plain))?;
+                        written.push(path);
+                }
+        }
+        Ok(written)
+}
+
+/// Decrypt each stored input in `stored_dir` into `plain_dir`, returning the files written.
+///
+/// A plain input that differs from its stored copy is only replaced with `force`.
+#[instrument(skip(key))]
+pub fn decrypt_inputs(
+        key: &InputKey,
+        day: u8,
+        stored_dir: &Path,
+        plain_dir: &Path,
+        force: bool,
+) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        for stored_name in file_names(stored_dir)? {
+                let Some(name) = stored_name.strip_suffix(&format!(".{ENCRYPTED_EXT}")) else {
+                        continue;
+                };
+                let stored_path = stored_dir.join(&stored_name);
+                let plain = key
+                        .decrypt(day, name, &read(&stored_path)?)
+                        .ok_or(ErrKindAoc::Decrypt { path: stored_path })?;
+                let path = plain_dir.join(name);
+                match fs::read(&path) {
+                        Ok(existing) if existing == plain => continue,
+                        Ok(_) if !force => return Err(ErrKindAoc::PlainInputDiffers { path }.into()),
+                        _ => {}
+                }
+                fs::create_dir_all(plain_dir)?;
+                fs::write(&path, plain)?;
+                written.push(path);
+        }
+        Ok(written)
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+        Ok(fs::read(path).map_err(|source| ErrKindAoc::InputFileRead { path: path.to_path_buf(), source })?)
+}
+
+/// Names of the regular, non-hidden files in `dir`, sorted; none if it does not exist.
+fn file_names(dir: &Path) -> Result<Vec<String>> {
+        if !dir.is_dir() {
+                return Ok(Vec::new());
+        }
+        let mut names = Vec::new();
+        for entry in fs::read_dir(dir)? {
+                let entry = entry?;
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if entry.file_type()?.is_file() && !name.starts_with('.') {
+                        names.push(name);
+                }
+        }
+        names.sort();
+        Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+        #[test]
+        fn key_hex_round_trip() -> Result<()> {
+                assert_eq!(InputKey::from_hex(KEY)?.to_hex(), KEY);
+                assert_eq!(InputKey::generate().to_hex().len(), 64);
+                // (`u8::from_str_radix` would take a leading `+`)
+                for bad in ["", "00", &KEY.replace('0', "g"), &format!("{KEY}00"), &format!("+{}", &KEY[1..])] {
+                        assert!(InputKey::from_hex(bad).is_err(), "{bad}");
+                }
+                assert_eq!(format!("{:?}", InputKey::from_hex(KEY)?), "InputKey(..)");
+                Ok(())
+        }
+
+        #[test]
+        fn encrypt_round_trip() -> Result<()> {
+                let key = InputKey::from_hex(KEY)?;
+                let stored = key.encrypt(6, "final_input.txt", b"....#.....\n");
+                assert!(stored.starts_with(MAGIC));
+                assert_eq!(key.decrypt(6, "final_input.txt", &stored), Some(b"....#.....\n".to_vec()));
+                // bound to day & name, and to the key
+                assert_eq!(key.decrypt(7, "final_input.txt", &stored), None);
+                assert_eq!(key.decrypt(6, "custom_input.txt", &stored), None);
+                assert_eq!(InputKey::generate().decrypt(6, "final_input.txt", &stored), None);
+                assert_eq!(key.decrypt(6, "final_input.txt", b"aocenc01"), None);
+                Ok(())
+        }
+
+        #[test]
+        fn encrypt_and_decrypt_dirs() -> Result<()> {
+                let scratch = std::env::temp_dir().join(format!("aoc-crypt-{}", std::process::id()));
+                let _ = fs::remove_dir_all(&scratch);
+                let (plain_dir, stored_dir, restored_dir) =
+                        (scratch.join("plain"), scratch.join("stored"), scratch.join("restored"));
+                fs::create_dir_all(&plain_dir)?;
+                fs::write(plain_dir.join("final_input.txt"), "1 2 3\n")?;
+                fs::write(plain_dir.join(".gitkeep"), "")?;
+                fs::write(plain_dir.join("notenc"), "4 5 6\n")?;
+                fs::write(plain_dir.join("stale.txt.enc"), "aocenc01")?;
+                let key = InputKey::from_hex(KEY)?;
+
+                let written = encrypt_inputs(&key, 1, &plain_dir, &stored_dir)?;
+                assert_eq!(written, [stored_dir.join("final_input.txt.enc"), stored_dir.join("notenc.enc")]);
+                assert_eq!(encrypt_inputs(&key, 1, &plain_dir, &stored_dir)?, Vec::<PathBuf>::new());
+
+                decrypt_inputs(&key, 1, &stored_dir, &restored_dir, false)?;
+                assert_eq!(fs::read_to_string(restored_dir.join("final_input.txt"))?, "1 2 3\n");
+                fs::write(restored_dir.join("final_input.txt"), "edited\n")?;
+                assert!(decrypt_inputs(&key, 1, &stored_dir, &restored_dir, false).is_err());
+                assert_eq!(decrypt_inputs(&key, 1, &stored_dir, &restored_dir, true)?.len(), 1);
+                fs::remove_dir_all(&scratch)?;
+                Ok(())
+        }
+}
@@ -0,0 +1,257 @@
+//! Where puzzle inputs come from.
+//!
+//! Example & custom inputs are small and public, so days embed them.
+//! Full puzzle inputs are private: they are read at runtime from the input directory,
+//! `<input dir>/dayNN/<file>`, unless a day is built with its `embed-inputs` feature.
+//! A full input missing from there is decrypted from the day's `data/` directory, given the team key.
+//! (See [`InputKey`](crate::InputKey).)
+//!
+//! The input directory is, in order of precedence: an explicit path (e.g. `aoc --input-dir`),
+//! the [`INPUT_DIR_ENV`] environment variable, the [`Config`]'s `input_dir`, or the workspace's `data/no-sync/`.
+//!
+//! Any input can instead be given directly on the command line with [`InputPath`]. (`--input <PATH>`, `-` for stdin)
+//!
+//! All input text is normalized as it is loaded. (See [`normalize_input`].)
+
+use std::{borrow::Cow,
+          convert::Infallible,
+          env,
+          fmt::{self, Display, Write as _},
+          fs,
+          io::{self, Read as _},
+          path::{Path, PathBuf},
+          str::FromStr};
+
+use sha2::{Digest as _, Sha256};
+use tracing::{self as tea, instrument};
+
+use crate::{Config, Result,
+            crypt::{load_stored_input, stored_input_path},
+            normalize::normalize_input,
+            support::ErrKindAoc};
+
+/// Environment variable naming the input directory.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Input text, or where to find it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+        /// Text compiled into the binary.
+        Embedded(&'static str),
+        /// A file under the input directory: `<input dir>/day{day:02}/{name}`.
+        File { day: u8, name: &'static str },
+}
+impl InputSource {
+        /// Location of the input under `input_dir`, if it is read from a file.
+        pub fn path_in(&self, input_dir: &Path) -> Option<PathBuf> {
+                match self {
+                        InputSource::Embedded(_) => None,
+                        InputSource::File { day, name } => Some(input_dir.join(format!("day{day:02}")).join(name)),
+                }
+        }
+
+        /// Where the input is read from under `input_dir`: `embedded`, its file, or the file's encrypted copy.
+        pub fn origin_in(&self, input_dir: &Path) -> String {
+                match (self, self.path_in(input_dir)) {
+                        (InputSource::Embedded(_), _) => "embedded".to_string(),
+                        (&InputSource::File { day, name }, Some(path)) => {
+                                let stored = stored_input_path(day, name);
+                                match !path.exists() && stored.exists() {
+                                        true => stored.display().to_string(),
+                                        false => path.display().to_string(),
+                                }
+                        }
+                        (InputSource::File { .. }, None) => unreachable!("file sources always have a path"),
+                }
+        }
+
+        /// Input text, normalized, reading from `input_dir` if not embedded.
+        #[instrument(skip(self), fields(source = ?self))]
+        pub fn load_from(&self, input_dir: &Path) -> Result<Cow<'static, str>> {
+                match (self, self.path_in(input_dir)) {
+                        (InputSource::Embedded(text), _) => Ok(normalized(Cow::Borrowed(text))),
+                        (&InputSource::File { day, name }, Some(path)) => {
+                                tea::debug!(?path, "reading input");
+                                match fs::read_to_string(&path) {
+                                        Ok(text) => Ok(normalized(Cow::Owned(text))),
+                                        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                                                match load_stored_input(day, name)? {
+                                                        Some(text) => Ok(normalized(Cow::Owned(text))),
+                                                        None => Err(ErrKindAoc::InputFileNotFound { path })?,
+                                                }
+                                        }
+                                        Err(source) => Err(ErrKindAoc::InputFileRead { path, source })?,
+                                }
+                        }
+                        (InputSource::File { .. }, None) => unreachable!("file sources always have a path"),
+                }
+        }
+
+        /// Input text, normalized, reading from the [`default_input_dir`] if not embedded.
+        pub fn load(&self) -> Result<Cow<'static, str>> {
+                self.load_from(&default_input_dir())
+        }
+}
+
+/// Input named on the command line: a file path, or `-` for stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputPath {
+        Stdin,
+        File(PathBuf),
+}
+impl FromStr for InputPath {
+        type Err = Infallible;
+
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                Ok(match s {
+                        "-" => InputPath::Stdin,
+                        path => InputPath::File(PathBuf::from(path)),
+                })
+        }
+}
+impl Display for InputPath {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                        InputPath::Stdin => write!(f, "stdin"),
+                        InputPath::File(path) => write!(f, "{}", path.display()),
+                }
+        }
+}
+impl InputPath {
+        /// Read all input text, normalized.
+        #[instrument]
+        pub fn read(&self) -> Result<String> {
+                let text = match self {
+                        InputPath::Stdin => {
+                                let mut text = String::new();
+                                io::stdin().read_to_string(&mut text)?;
+                                text
+                        }
+                        InputPath::File(path) => fs::read_to_string(path)
+                                .map_err(|source| ErrKindAoc::InputFileRead { path: path.clone(), source })?,
+                };
+                Ok(normalized(Cow::Owned(text)).into_owned())
+        }
+}
+
+/// Input text, normalized; logging what was changed, if anything.
+fn normalized(text: Cow<'static, str>) -> Cow<'static, str> {
+        let fixed = match normalize_input(&text) {
+                (Cow::Owned(fixed), normalization) => Some((fixed, normalization)),
+                (Cow::Borrowed(_), _) => None,
+        };
+        match fixed {
+                Some((fixed, normalization)) => {
+                        tea::info!(%normalization, "input normalized");
+                        Cow::Owned(fixed)
+                }
+                None => text,
+        }
+}
+
+/// SHA-256 of input text, as lowercase hex. (Identifies an input without revealing it.)
+pub fn input_hash(text: &str) -> String {
+        Sha256::digest(text.as_bytes())
+                .iter()
+                .fold(String::with_capacity(64), |mut hex, byte| {
+                        let _ = write!(hex, "{byte:02x}");
+                        hex
+                })
+}
+
+/// An input's origin & hash, for output: `<origin> (sha256 <first 12 digits of the hash>)`.
+pub fn describe_input(origin: &str, input_hash: &str) -> String {
+        format!("{origin} (sha256 {})", input_hash.get(..12).unwrap_or(input_hash))
+}
+
+/// Input directory when none is given explicitly:
+/// [`INPUT_DIR_ENV`] if set, else the [`Config`]'s `input_dir`, else the workspace's `data/no-sync/`.
+///
+/// (A malformed config is skipped here; binaries load the [`Config`] up front, to report it.)
+pub fn default_input_dir() -> PathBuf {
+        env::var_os(INPUT_DIR_ENV)
+                .map(PathBuf::from)
+                .or_else(|| Config::load().ok().and_then(|config| config.input_dir.clone()))
+                .unwrap_or_else(workspace_input_dir)
+}
+
+/// The workspace's root directory, as located at compile time.
+pub fn workspace_dir() -> PathBuf {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR")); // `crates/aoc_core`
+        manifest_dir
+                .parent()
+                .and_then(Path::parent)
+                .unwrap_or(manifest_dir)
+                .to_path_buf()
+}
+
+/// The workspace's `data/no-sync/` directory, as located at compile time.
+fn workspace_input_dir() -> PathBuf {
+        workspace_dir().join("data").join("no-sync")
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        #[test]
+        fn embedded_ignores_dir() -> Result<()> {
+                let source = InputSource::Embedded("1 2 3");
+                assert_eq!(source.path_in(Path::new("/nowhere")), None);
+                // (normalized, so gains a final newline)
+                assert_eq!(source.load_from(Path::new("/nowhere"))?, "1 2 3\n");
+                Ok(())
+        }
+
+        #[test]
+        fn missing_file_names_path() {
+                let source = InputSource::File { day: 6, name: "final_input.txt" };
+                let err = source.load_from(Path::new("/nowhere")).expect_err("no such directory");
+                let expected = PathBuf::from("/nowhere/day06/final_input.txt");
+                assert!(matches!(&err.source, ErrKindAoc::InputFileNotFound { path } if *path == expected));
+                assert!(err.source.to_string().contains("/nowhere/day06/final_input.txt"));
+        }
+
+        #[test]
+        fn file_input_is_normalized() -> Result<()> {
+                let path = env::temp_dir().join(format!("aoc-input-crlf-{}.txt", std::process::id()));
+                fs::write(&path, "\u{feff}1 2\r\n3\r\n\r\n")?;
+                assert_eq!(InputPath::File(path.clone()).read()?, "1 2\n3\n");
+                fs::remove_file(&path)?;
+                Ok(())
+        }
+
+        #[test]
+        fn input_path_from_arg() {
+                assert_eq!("-".parse(), Ok(InputPath::Stdin));
+                assert_eq!("in.txt".parse(), Ok(InputPath::File(PathBuf::from("in.txt"))));
+                let err = InputPath::File(PathBuf::from("/nowhere/in.txt"))
+                        .read()
+                        .expect_err("no such file");
+                assert!(matches!(err.source, ErrKindAoc::InputFileRead { .. }));
+        }
+
+        #[test]
+        fn input_hash_is_sha256_hex() {
+                assert_eq!(input_hash(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+                assert_ne!(input_hash("1 2 3\n"), input_hash("1 2 3"));
+                assert_eq!(describe_input("stdin", &input_hash("")), "stdin (sha256 e3b0c44298fc)");
+        }
+
+        #[test]
+        fn input_origins() {
+                assert_eq!(InputSource::Embedded("1 2 3").origin_in(Path::new("/nowhere")), "embedded");
+                let source = InputSource::File { day: 6, name: "no_such_input.txt" };
+                assert_eq!(source.origin_in(Path::new("/nowhere")), "/nowhere/day06/no_such_input.txt");
+        }
+
+        #[test]
+        fn workspace_dir_is_data_no_sync() {
+                let dir = workspace_input_dir();
+                assert!(dir.ends_with("data/no-sync"));
+                assert!(dir.join("..").join("..").join("Cargo.toml").exists());
+        }
+}
@@ -0,0 +1,29 @@
+//! Shared code for the Advent of Code 2024 workspace.
+//!
+//! Each day crate implements [`Solution`]; the `aoc` runner holds them as [`DynSolution`]s in a single registry.
+
+mod answers;
+mod bench;
+mod cli_docs;
+mod config;
+mod crypt;
+mod input;
+mod normalize;
+mod report;
+mod runner;
+mod solution;
+mod support;
+mod variant;
+
+pub use answers::{Answers, Expected, PartAnswers, PinnedAnswer, RecordedAnswer};
+pub use bench::{BenchInput, bench_inputs};
+pub use cli_docs::CliDocs;
+pub use config::{CONFIG_ENV, Config, DayConfig, LogConfig};
+pub use crypt::{ENCRYPTED_EXT, INPUT_KEY_ENV, InputKey, decrypt_inputs, encrypt_inputs, stored_input_dir};
+pub use input::{INPUT_DIR_ENV, InputPath, InputSource, default_input_dir, describe_input, input_hash, workspace_dir};
+pub use normalize::{Normalization, normalize_input};
+pub use report::{OutputFormat, RunReport};
+pub use runner::{IsolatedRun, Outcome, run_isolated};
+pub use solution::{DynError, DynParsed, DynSolution, InputKind, Part, Parts, Solution, Solved, SolvedBoth};
+pub use support::{ErrKindAoc, Error, Result, activate_global_default_tracing_subscriber};
+pub use variant::{DEFAULT_VARIANT, Variant, Variants};
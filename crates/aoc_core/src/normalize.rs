@@ -0,0 +1,150 @@
+//! Input normalization, so inputs edited on Windows or copy-pasted from a browser parse like the originals.
+//!
+//! Applied to every loaded input (see [`InputSource`](crate::InputSource) & [`InputPath`](crate::InputPath)), and
+//! again before every [`DynSolution::parse`](crate::DynSolution::parse). It is idempotent, and borrows if nothing
+//! changed.
+//!
+//! Normalized text has no byte order mark, only `\n` line endings, no trailing whitespace on any line, and ends with
+//! exactly one newline (unless empty). Leading whitespace and blank lines within the text are kept.
+
+use std::{borrow::Cow, fmt};
+
+use serde::Serialize;
+
+/// Byte order mark, as left at the start of files by some Windows editors.
+const BOM: char = '\u{feff}';
+
+/// What [`normalize_input`] changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Normalization {
+        /// A byte order mark was removed.
+        pub bom:                       bool,
+        /// Lines whose `\r\n` ending became `\n`.
+        pub crlf_lines:                usize,
+        /// Lines with trailing whitespace removed.
+        pub trailing_whitespace_lines: usize,
+        /// Blank lines removed from the end.
+        pub trailing_blank_lines:      usize,
+        /// A newline was added at the end.
+        pub final_newline_added:       bool,
+}
+impl Normalization {
+        pub fn is_unchanged(&self) -> bool {
+                *self == Self::default()
+        }
+}
+impl fmt::Display for Normalization {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut changes = Vec::new();
+                if self.bom {
+                        changes.push("removed byte order mark".to_string());
+                }
+                if self.crlf_lines > 0 {
+                        changes.push(format!("converted {} CRLF line ending(s)", self.crlf_lines));
+                }
+                if self.trailing_whitespace_lines > 0 {
+                        changes.push(format!(
+                                "trimmed trailing whitespace on {} line(s)",
+                                self.trailing_whitespace_lines
+                        ));
+                }
+                if self.trailing_blank_lines > 0 {
+                        changes.push(format!("removed {} trailing blank line(s)", self.trailing_blank_lines));
+                }
+                if self.final_newline_added {
+                        changes.push("added final newline".to_string());
+                }
+                match changes.is_empty() {
+                        true => write!(f, "unchanged"),
+                        false => write!(f, "{}", changes.join(", ")),
+                }
+        }
+}
+
+/// Normalize input text, reporting what changed.
+pub fn normalize_input(text: &str) -> (Cow<'_, str>, Normalization) {
+        let mut report = Normalization::default();
+        let body = match text.strip_prefix(BOM) {
+                Some(body) => {
+                        report.bom = true;
+                        body
+                }
+                None => text,
+        };
+
+        let mut lines: Vec<&str> = body
+                .split_inclusive('\n')
+                .map(|line| {
+                        let (line, newline) = match line.strip_suffix('\n') {
+                                Some(line) => (line, true),
+                                None => (line, false),
+                        };
+                        let (line, crlf) = match (newline, line.strip_suffix('\r')) {
+                                (true, Some(line)) => (line, true),
+                                _ => (line, false),
+                        };
+                        report.crlf_lines += usize::from(crlf);
+                        let trimmed = line.trim_end();
+                        report.trailing_whitespace_lines += usize::from(trimmed.len() != line.len());
+                        trimmed
+                })
+                .collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+                lines.pop();
+                report.trailing_blank_lines += 1;
+        }
+        // (if blank lines were removed, the last line kept had a newline)
+        report.final_newline_added = !lines.is_empty() && report.trailing_blank_lines == 0 && !body.ends_with('\n');
+
+        let mut normalized = lines.join("\n");
+        if !normalized.is_empty() {
+                normalized.push('\n');
+        }
+        match normalized == text {
+                true => (Cow::Borrowed(text), Normalization::default()),
+                false => (Cow::Owned(normalized), report),
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        #[test]
+        fn clean_input_is_borrowed() {
+                for text in ["", "a\n", "a\n\nb\n", "  indented\n"] {
+                        let (normalized, report) = normalize_input(text);
+                        assert!(matches!(normalized, Cow::Borrowed(_)), "{text:?}");
+                        assert!(report.is_unchanged(), "{text:?}");
+                }
+        }
+
+        #[test]
+        fn windows_input() {
+                let (normalized, report) = normalize_input("\u{feff}47|53\r\n97|13 \r\n\r\n75,47\r\n\r\n\r\n");
+                assert_eq!(normalized, "47|53\n97|13\n\n75,47\n");
+                assert_eq!(report, Normalization {
+                        bom:                       true,
+                        crlf_lines:                6,
+                        trailing_whitespace_lines: 1,
+                        trailing_blank_lines:      2,
+                        final_newline_added:       false,
+                });
+                assert_eq!(
+                        report.to_string(),
+                        "removed byte order mark, converted 6 CRLF line ending(s), trimmed trailing whitespace on 1 \
+                         line(s), removed 2 trailing blank line(s)"
+                );
+        }
+
+        #[test]
+        fn pasted_input() {
+                let (normalized, report) = normalize_input("MMMS\nXMAS\t");
+                assert_eq!(normalized, "MMMS\nXMAS\n");
+                assert_eq!((report.trailing_whitespace_lines, report.final_newline_added), (1, true));
+                assert_eq!(normalize_input(&normalized).0, Cow::Borrowed(normalized.as_ref()));
+        }
+}
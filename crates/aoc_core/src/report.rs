@@ -0,0 +1,649 @@
+//! Reports of solver runs: printed as text or JSON (`--format`), and checked against an expected answer (`--expect`).
+//!
+//! A [`RunReport`] records what was run, on which input, and how it ended, so results can be aggregated & diffed.
+//! Inputs are identified by label, origin and [`input_hash`], never by content.
+//! How a run ended is also given as a [`RunExit`], so scripts can tell failures apart by exit code alone.
+
+use std::{borrow::Cow,
+          panic::{self, AssertUnwindSafe},
+          path::Path,
+          process::ExitCode,
+          time::{Duration, Instant}};
+
+use clap::ValueEnum;
+use derive_more::derive::Display;
+use owo_colors::{OwoColorize as _,
+                 Stream::{Stderr, Stdout},
+                 Style};
+use serde::Serialize;
+
+use crate::{DynSolution, ErrKindAoc, InputKind, InputPath, Outcome, Part, Parts, Result, Solved, SolvedBoth, Variants,
+            input::{describe_input, input_hash},
+            runner::panic_message};
+
+/// How a solver run is printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Display)]
+pub enum OutputFormat {
+        /// Human-readable text.
+        #[default]
+        #[display("text")]
+        Text,
+        /// JSON, for scripts.
+        #[display("json")]
+        Json,
+}
+
+/// How a run ended, as a process exit code. (Clap exits with `2` on bad arguments.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Display)]
+#[serde(into = "u8")]
+#[repr(u8)]
+pub enum RunExit {
+        /// Answered, and matched any expected answer.
+        #[display("ok")]
+        Ok               = 0,
+        /// Failed outside the day's own code. (e.g. input missing, or an error of unknown phase in a batch run)
+        #[display("error")]
+        Error            = 1,
+        #[display("parse error")]
+        ParseError       = 3,
+        #[display("solve error")]
+        SolveError       = 4,
+        /// Answered, but not with the expected answer.
+        #[display("wrong answer")]
+        WrongAnswer      = 5,
+        /// Answered, but variants of the part gave different answers. (`--all-variants`)
+        #[display("variants disagree")]
+        VariantsDisagree = 6,
+        #[display("panic")]
+        Panic            = 101,
+        #[display("timeout")]
+        Timeout          = 124,
+}
+impl From<RunExit> for u8 {
+        fn from(exit: RunExit) -> Self {
+                exit as u8
+        }
+}
+impl From<RunExit> for ExitCode {
+        fn from(exit: RunExit) -> Self {
+                ExitCode::from(exit as u8)
+        }
+}
+
+/// One solver run: what was run, on which input, and how it ended.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunReport {
+        pub day:          u8,
+        pub part:         u8,
+        /// Where the input came from. (e.g. `example`, `full`, `stdin`, or a path)
+        pub input:        String,
+        /// [`input_hash`] of the input text; absent if it could not be loaded.
+        pub input_hash:   Option<String>,
+        /// Where the input text was read from: a path, `stdin`, or `embedded`. (See [`DynSolution::input_origin`].)
+        pub input_source: Option<String>,
+        /// `ok`, `err`, `panic`, or `timeout`.
+        pub status:       String,
+        pub answer:       Option<String>,
+        /// Answer the run was checked against, if any.
+        pub expected:     Option<String>,
+        pub parse_secs:   Option<f64>,
+        pub solve_secs:   Option<f64>,
+        /// Solved together with the other part, from one parse; times then cover both parts.
+        pub shared:       bool,
+        /// Variant of the part that was run, if one was chosen. (See [`Variants`].)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub variant:      Option<String>,
+        pub error:        Option<String>,
+        /// Exit code for the run. (See [`RunExit`].)
+        pub exit_code:    RunExit,
+}
+impl RunReport {
+        /// Report on a finished run.
+        ///
+        /// Errors are reported as [`RunExit::Error`], as their phase is not known from the outcome alone.
+        pub fn new(day: u8, part: Part, input: String, input_hash: Option<String>, outcome: &Outcome<Solved>) -> Self {
+                let (answer, parse_secs, solve_secs) = match outcome {
+                        Outcome::Ok(solved) => (
+                                Some(solved.answer.clone()),
+                                Some(solved.parse_time.as_secs_f64()),
+                                Some(solved.solve_time.as_secs_f64()),
+                        ),
+                        _ => (None, None, None),
+                };
+                let (error, exit_code) = match outcome {
+                        Outcome::Ok(_) => (None, RunExit::Ok),
+                        Outcome::Err(msg) => (Some(msg.clone()), RunExit::Error),
+                        Outcome::Panic(msg) => (Some(msg.clone()), RunExit::Panic),
+                        Outcome::Timeout => (Some("no result within the time limit".to_string()), RunExit::Timeout),
+                };
+                let part = match part {
+                        Part::Part1 => 1,
+                        Part::Part2 => 2,
+                };
+                Self {
+                        day,
+                        part,
+                        input,
+                        input_hash,
+                        input_source: None,
+                        status: outcome.to_string().to_lowercase(),
+                        answer,
+                        expected: None,
+                        parse_secs,
+                        solve_secs,
+                        shared: false,
+                        variant: None,
+                        error,
+                        exit_code,
+                }
+        }
+
+        /// Solve `part` of a day on loaded (or failed to load) input, reporting any error or panic rather than returning
+        /// it, and checking the answer against `expected` if given.
+        pub fn run(
+                solution: &dyn DynSolution,
+                part: Part,
+                (input, text): (String, Result<Cow<'_, str>>),
+                expected: Option<&str>,
+        ) -> Self {
+                let day = solution.day();
+                let text = match text {
+                        Ok(text) => text,
+                        Err(err) => return Self::new(day, part, input, None, &Outcome::Err(err.source.to_string())),
+                };
+                let (outcome, exit_code) = guarded(|| solution.solve(part, &text));
+                Self::new(day, part, input, Some(input_hash(&text)), &outcome).finish(exit_code, expected)
+        }
+
+        /// As [`RunReport::run`], for both parts from a single parse.
+        pub fn run_both(
+                solution: &dyn DynSolution,
+                (input, text): (String, Result<Cow<'_, str>>),
+                expected: [Option<&str>; 2],
+        ) -> [Self; 2] {
+                let day = solution.day();
+                let text = match text {
+                        Ok(text) => text,
+                        Err(err) => {
+                                let outcome = Outcome::Err(err.source.to_string());
+                                return [Part::Part1, Part::Part2]
+                                        .map(|part| Self::new(day, part, input.clone(), None, &outcome));
+                        }
+                };
+                let hash = input_hash(&text);
+                let (outcome, exit_code) = guarded(|| solution.solve_both(&text));
+                let [part1, part2] = [(Part::Part1, 0), (Part::Part2, 1)].map(|(part, idx)| {
+                        let outcome = match &outcome {
+                                Outcome::Ok(SolvedBoth { answers, parse_time, solve_time }) => Outcome::Ok(Solved {
+                                        answer:     answers[idx].clone(),
+                                        parse_time: *parse_time,
+                                        solve_time: *solve_time,
+                                }),
+                                Outcome::Err(msg) => Outcome::Err(msg.clone()),
+                                Outcome::Panic(msg) => Outcome::Panic(msg.clone()),
+                                Outcome::Timeout => Outcome::Timeout,
+                        };
+                        let mut report = Self::new(day, part, input.clone(), Some(hash.clone()), &outcome)
+                                .finish(exit_code, expected[idx]);
+                        report.shared = true;
+                        report
+                });
+                [part1, part2]
+        }
+
+        /// As [`RunReport::run`], for each of the named `variants` of `part`, from a single parse.
+        ///
+        /// Parse time is that of the one parse, in every report.
+        /// If variants answer differently, those that answered end [`RunExit::VariantsDisagree`].
+        pub fn run_variants(
+                solution: &dyn DynSolution,
+                part: Part,
+                (input, text): (String, Result<Cow<'_, str>>),
+                variants: &[&str],
+                expected: Option<&str>,
+        ) -> Vec<Self> {
+                let day = solution.day();
+                let with_variant = |mut report: Self, variant: &str| {
+                        report.variant = Some(variant.to_string());
+                        report
+                };
+                let text = match text {
+                        Ok(text) => text,
+                        Err(err) => {
+                                let outcome = Outcome::Err(err.source.to_string());
+                                return variants
+                                        .iter()
+                                        .map(|variant| {
+                                                with_variant(
+                                                        Self::new(day, part, input.clone(), None, &outcome),
+                                                        variant,
+                                                )
+                                        })
+                                        .collect();
+                        }
+                };
+                let hash = input_hash(&text);
+                let start = Instant::now();
+                let (parsed, parse_exit_code) = guarded(|| solution.parse(&text));
+                let parse_time = start.elapsed();
+                let mut reports: Vec<Self> = variants
+                        .iter()
+                        .map(|&variant| {
+                                let (outcome, exit_code) = match &parsed {
+                                        Outcome::Ok(parsed) => {
+                                                let start = Instant::now();
+                                                let (outcome, exit_code) = guarded(|| {
+                                                        solution.solve_variant_parsed(part, variant, parsed)
+                                                });
+                                                let solve_time = start.elapsed();
+                                                let outcome = match outcome {
+                                                        Outcome::Ok(answer) => {
+                                                                Outcome::Ok(Solved { answer, parse_time, solve_time })
+                                                        }
+                                                        Outcome::Err(msg) => Outcome::Err(msg),
+                                                        Outcome::Panic(msg) => Outcome::Panic(msg),
+                                                        Outcome::Timeout => Outcome::Timeout,
+                                                };
+                                                (outcome, exit_code)
+                                        }
+                                        Outcome::Err(msg) => (Outcome::Err(msg.clone()), parse_exit_code),
+                                        Outcome::Panic(msg) => (Outcome::Panic(msg.clone()), parse_exit_code),
+                                        Outcome::Timeout => (Outcome::Timeout, parse_exit_code),
+                                };
+                                let report = Self::new(day, part, input.clone(), Some(hash.clone()), &outcome)
+                                        .finish(exit_code, expected);
+                                with_variant(report, variant)
+                        })
+                        .collect();
+                let answers: Vec<&str> = reports.iter().filter_map(|report| report.answer.as_deref()).collect();
+                if answers.iter().any(|&answer| answer != answers[0]) {
+                        for report in reports.iter_mut().filter(|report| report.is_ok()) {
+                                report.exit_code = RunExit::VariantsDisagree;
+                        }
+                }
+                reports
+        }
+
+        /// Run the chosen part(s) on input from `path` if given, else the day's own `kind` of input.
+        ///
+        /// Both parts share a parse where they share an input. (Some days' example inputs differ between parts.)
+        /// With a variant (or all variants) chosen, parts are run separately, each variant from one parse of its input.
+        /// `expected` answers are for the chosen parts, in order. Each report records where its input came from.
+        pub fn run_parts(
+                solution: &dyn DynSolution,
+                parts: Parts,
+                path: Option<&InputPath>,
+                kind: InputKind,
+                input_dir: &Path,
+                expected: &[String],
+                variants: &Variants,
+        ) -> Result<Vec<Self>> {
+                let mut reports = Self::run_chosen_parts(solution, parts, path, kind, input_dir, expected, variants)?;
+                for report in &mut reports {
+                        let part = match report.part {
+                                1 => Part::Part1,
+                                _ => Part::Part2,
+                        };
+                        report.input_source = Some(solution.input_origin(part, path, kind, input_dir));
+                }
+                Ok(reports)
+        }
+
+        fn run_chosen_parts(
+                solution: &dyn DynSolution,
+                parts: Parts,
+                path: Option<&InputPath>,
+                kind: InputKind,
+                input_dir: &Path,
+                expected: &[String],
+                variants: &Variants,
+        ) -> Result<Vec<Self>> {
+                let chosen: &[Part] = match parts.single() {
+                        Some(Part::Part1) => &[Part::Part1],
+                        Some(Part::Part2) => &[Part::Part2],
+                        None => &[Part::Part1, Part::Part2],
+                };
+                if expected.len() > chosen.len() {
+                        Err(ErrKindAoc::ExpectedCount { given: expected.len(), parts: chosen.len() })?
+                }
+                let expected = |idx: usize| expected.get(idx).map(String::as_str);
+                if !variants.is_default() {
+                        let mut reports = Vec::new();
+                        for (idx, &part) in chosen.iter().enumerate() {
+                                let names = match &variants.variant {
+                                        Some(name) if !solution.variants(part).contains(&name.as_str()) => {
+                                                Err(solution.unknown_variant(part, name))?
+                                        }
+                                        Some(name) => vec![name.as_str()],
+                                        None => solution.variants(part),
+                                };
+                                let input = solution.select_input(part, path, kind, input_dir);
+                                reports.extend(Self::run_variants(solution, part, input, &names, expected(idx)));
+                        }
+                        return Ok(reports);
+                }
+                let shared_input =
+                        path.is_some() || solution.input(kind, Part::Part1) == solution.input(kind, Part::Part2);
+                if parts == Parts::Both && shared_input {
+                        let input = solution.select_input(Part::Part1, path, kind, input_dir);
+                        return Ok(Self::run_both(solution, input, [expected(0), expected(1)]).into());
+                }
+                Ok(chosen
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, &part)| {
+                                Self::run(
+                                        solution,
+                                        part,
+                                        solution.select_input(part, path, kind, input_dir),
+                                        expected(idx),
+                                )
+                        })
+                        .collect())
+        }
+
+        /// Set the exit code for how the run ended, then check the answer against `expected` if given.
+        fn finish(mut self, exit_code: RunExit, expected: Option<&str>) -> Self {
+                self.exit_code = exit_code;
+                if let Some(expected) = expected {
+                        self.expected = Some(expected.to_string());
+                        if self.answer.as_ref().is_some_and(|answer| answer != expected) {
+                                self.exit_code = RunExit::WrongAnswer;
+                        }
+                }
+                self
+        }
+
+        /// Print reports in the given format, returning the exit code of the first that did not end [`RunExit::Ok`].
+        ///
+        /// JSON is a single object for one report, or an array for several.
+        pub fn print_all(reports: &[Self], format: OutputFormat) -> Result<ExitCode> {
+                match (format, reports) {
+                        (OutputFormat::Json, [report]) => println!("{}", report.to_json()?),
+                        (OutputFormat::Json, _) => println!("{}", serde_json::to_string_pretty(reports)?),
+                        (OutputFormat::Text, [report]) => report.print_text(),
+                        (OutputFormat::Text, _) => {
+                                for report in reports {
+                                        let variant = report.variant.as_ref().map(|variant| format!(" ({variant})"));
+                                        println!(
+                                                "{}",
+                                                format!("Part {}{}", report.part, variant.unwrap_or_default())
+                                                        .if_supports_color(Stdout, |text| text.bold())
+                                        );
+                                        report.print_text();
+                                }
+                        }
+                }
+                let exit_code = reports
+                        .iter()
+                        .map(|report| report.exit_code)
+                        .find(|&exit_code| exit_code != RunExit::Ok)
+                        .unwrap_or(RunExit::Ok);
+                Ok(exit_code.into())
+        }
+
+        /// Answer & timings (or error) on stdout, with a coloured diff if the answer was not the expected one.
+        ///
+        /// Colour is only used where the stream supports it. (Not when redirected, nor with `NO_COLOR` set.)
+        fn print_text(&self) {
+                if let Some(error) = &self.error {
+                        eprintln!(
+                                "{} {error}",
+                                format!("{}:", self.exit_code)
+                                        .if_supports_color(Stderr, |text| text.style(Style::new().red().bold()))
+                        );
+                        return;
+                }
+                let answer = self.answer.as_deref().unwrap_or_default();
+                println!("Calculated solution: {answer}");
+                if let (Some(parse), Some(solve)) = (self.parse_secs, self.solve_secs) {
+                        let (parse, solve) = (Duration::from_secs_f64(parse), Duration::from_secs_f64(solve));
+                        println!("Parse: {parse:.2?}  Solve: {solve:.2?}");
+                }
+                if let Some(hash) = &self.input_hash {
+                        let origin = self.input_source.as_deref().unwrap_or(&self.input);
+                        println!(
+                                "{}",
+                                format!("Input: {}", describe_input(origin, hash))
+                                        .if_supports_color(Stdout, |text| text.dimmed())
+                        );
+                }
+                match (&self.expected, self.exit_code) {
+                        (_, RunExit::VariantsDisagree) => {
+                                println!(
+                                        "{}",
+                                        "answer differs from other variants'".if_supports_color(Stdout, |text| text
+                                                .style(Style::new().red().bold()))
+                                )
+                        }
+                        (Some(_), RunExit::Ok) => {
+                                println!("{}", "matches expected answer".if_supports_color(Stdout, |text| text.green()))
+                        }
+                        (Some(expected), _) => {
+                                println!(
+                                        "{}",
+                                        "answer differs from expected:".if_supports_color(Stdout, |text| text
+                                                .style(Style::new().red().bold()))
+                                );
+                                println!(
+                                        "{}",
+                                        format!("- expected: {expected}").if_supports_color(Stdout, |text| text.red())
+                                );
+                                println!(
+                                        "{}",
+                                        format!("+ got:      {answer}").if_supports_color(Stdout, |text| text.green())
+                                );
+                        }
+                        (None, _) => {}
+                }
+        }
+
+        pub fn is_ok(&self) -> bool {
+                self.exit_code == RunExit::Ok
+        }
+
+        /// Pretty-printed JSON.
+        pub fn to_json(&self) -> Result<String> {
+                Ok(serde_json::to_string_pretty(self)?)
+        }
+}
+
+/// Run `solve`, catching panics, with the exit code for how it ended.
+fn guarded<T>(solve: impl FnOnce() -> Result<T>) -> (Outcome<T>, RunExit) {
+        match panic::catch_unwind(AssertUnwindSafe(solve)) {
+                Ok(Ok(solved)) => (Outcome::Ok(solved), RunExit::Ok),
+                Ok(Err(err)) => {
+                        let exit_code = match err.source {
+                                ErrKindAoc::Parse { .. } => RunExit::ParseError,
+                                ErrKindAoc::Day { .. } => RunExit::SolveError,
+                                _ => RunExit::Error,
+                        };
+                        (Outcome::Err(err.source.to_string()), exit_code)
+                }
+                Err(payload) => (Outcome::Panic(panic_message(payload.as_ref())), RunExit::Panic),
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+        use crate::{Answers, DynParsed, InputSource};
+
+        #[test]
+        fn ok_report_json() -> Result<()> {
+                let solved = Solved {
+                        answer:     "42".to_string(),
+                        parse_time: Duration::from_millis(500),
+                        solve_time: Duration::from_secs(2),
+                };
+                let report = RunReport::new(6, Part::Part2, "full".to_string(), None, &Outcome::Ok(solved));
+                let json: serde_json::Value = serde_json::from_str(&report.to_json()?)?;
+                assert_eq!(
+                        json,
+                        serde_json::json!({
+                                "day": 6, "part": 2, "input": "full", "input_hash": null, "input_source": null,
+                                "status": "ok",
+                                "answer": "42", "expected": null, "parse_secs": 0.5, "solve_secs": 2.0, "shared": false,
+                                "error": null, "exit_code": 0,
+                        })
+                );
+                Ok(())
+        }
+
+        #[test]
+        fn load_error_report() {
+                let err = ErrKindAoc::UnregisteredDay { day: 26 };
+                let report = RunReport::run(&Sum, Part::Part1, ("stdin".to_string(), Err(err.into())), None);
+                assert!(!report.is_ok());
+                assert_eq!((report.status.as_str(), report.input_hash), ("err", None));
+                assert_eq!(report.error.as_deref(), Some("No solution registered for day 26"));
+        }
+
+        #[test]
+        fn exit_codes() {
+                let run = |text: &'static str, expected| {
+                        RunReport::run(&Sum, Part::Part1, ("test".to_string(), Ok(Cow::Borrowed(text))), expected)
+                                .exit_code
+                };
+                assert_eq!(run("1 2 3", None), RunExit::Ok);
+                assert_eq!(run("1 2 3", Some("6")), RunExit::Ok);
+                assert_eq!(run("1 2 3", Some("7")), RunExit::WrongAnswer);
+                assert_eq!(run("1 two 3", Some("6")), RunExit::ParseError);
+                assert_eq!(run("", None), RunExit::SolveError);
+                assert_eq!(run("0", None), RunExit::Panic);
+        }
+
+        #[test]
+        fn both_parts() -> Result<()> {
+                let run = |kind, expected: &[String]| {
+                        RunReport::run_parts(
+                                &Sum,
+                                Parts::Both,
+                                None,
+                                kind,
+                                Path::new(""),
+                                expected,
+                                &Variants::default(),
+                        )
+                };
+                let shared = run(InputKind::Example, &[])?;
+                assert_eq!(
+                        shared.iter()
+                                .map(|r| (r.part, r.answer.as_deref(), r.shared))
+                                .collect::<Vec<_>>(),
+                        [(1, Some("6"), true), (2, Some("6"), true)]
+                );
+                assert!(shared.iter().all(|r| r.input_source.as_deref() == Some("embedded")
+                        && r.input_hash.as_deref() == Some(input_hash("1 2 3\n").as_str())));
+                // custom inputs differ between parts, so are parsed separately
+                let separate = run(InputKind::Custom, &["3".to_string(), "8".to_string()])?;
+                assert_eq!(
+                        separate.iter()
+                                .map(|r| (r.answer.as_deref(), r.shared, r.exit_code))
+                                .collect::<Vec<_>>(),
+                        [(Some("3"), false, RunExit::Ok), (Some("7"), false, RunExit::WrongAnswer)]
+                );
+                let too_many = vec!["1".to_string(); 3];
+                assert!(run(InputKind::Example, &too_many).is_err());
+                Ok(())
+        }
+
+        #[test]
+        fn variants_cross_checked() -> Result<()> {
+                let run = |parts, variant: Option<&str>| {
+                        let variants =
+                                Variants { variant: variant.map(str::to_string), all_variants: variant.is_none() };
+                        RunReport::run_parts(&Sum, parts, None, InputKind::Example, Path::new(""), &[], &variants)
+                };
+                let summary = |reports: Vec<RunReport>| {
+                        reports.into_iter()
+                                .map(|r| (r.part, r.variant, r.answer, r.exit_code))
+                                .collect::<Vec<_>>()
+                };
+                let variant = |name: &str| Some(name.to_string());
+                let answer = |answer: &str| Some(answer.to_string());
+                assert_eq!(summary(run(Parts::Part1, None)?), [(1, variant("default"), answer("6"), RunExit::Ok)]);
+                assert_eq!(summary(run(Parts::Part2, None)?), [
+                        (2, variant("default"), answer("6"), RunExit::VariantsDisagree),
+                        (2, variant("plus-one"), answer("7"), RunExit::VariantsDisagree),
+                ]);
+                assert_eq!(summary(run(Parts::Part2, Some("plus-one"))?), [(
+                        2,
+                        variant("plus-one"),
+                        answer("7"),
+                        RunExit::Ok
+                )]);
+                // part 1 has no such variant
+                assert!(run(Parts::Both, Some("plus-one")).is_err());
+                Ok(())
+        }
+
+        /// Sums whitespace separated numbers; errors on no numbers, and panics on a zero.
+        /// Part 2 has a second variant, answering one more.
+        struct Sum;
+        impl DynSolution for Sum {
+                fn day(&self) -> u8 {
+                        26
+                }
+
+                fn title(&self) -> &'static str {
+                        "Sum"
+                }
+
+                fn input(&self, kind: InputKind, part: Part) -> Option<InputSource> {
+                        match (kind, part) {
+                                (InputKind::Example, _) => Some(InputSource::Embedded("1 2 3")),
+                                (InputKind::Custom, Part::Part1) => Some(InputSource::Embedded("1 2")),
+                                (InputKind::Custom, Part::Part2) => Some(InputSource::Embedded("3 4")),
+                                (InputKind::Full, _) => None,
+                        }
+                }
+
+                fn answers(&self) -> Result<Answers> {
+                        Ok(Answers::default())
+                }
+
+                fn parse(&self, input: &str) -> Result<DynParsed> {
+                        let numbers: Vec<u64> = input
+                                .split_whitespace()
+                                .map(str::parse)
+                                .collect::<std::result::Result<_, _>>()
+                                .map_err(|e| ErrKindAoc::Parse { day: 26, source: Box::new(e) })?;
+                        Ok(Box::new(numbers))
+                }
+
+                fn solve_parsed(&self, _part: Part, parsed: &DynParsed) -> Result<String> {
+                        let numbers = parsed.downcast_ref::<Vec<u64>>().expect("parsed by `Sum`");
+                        assert!(!numbers.contains(&0), "zero");
+                        match numbers.is_empty() {
+                                true => Err(ErrKindAoc::Day { day: 26, source: "no numbers".into() })?,
+                                false => Ok(numbers.iter().sum::<u64>().to_string()),
+                        }
+                }
+
+                fn solve_both_parsed(&self, parsed: &DynParsed) -> Result<[String; 2]> {
+                        Ok([self.solve_parsed(Part::Part1, parsed)?, self.solve_parsed(Part::Part2, parsed)?])
+                }
+
+                fn variants(&self, part: Part) -> Vec<&'static str> {
+                        match part {
+                                Part::Part1 => vec!["default"],
+                                Part::Part2 => vec!["default", "plus-one"],
+                        }
+                }
+
+                fn solve_variant_parsed(&self, part: Part, variant: &str, parsed: &DynParsed) -> Result<String> {
+                        let answer = self.solve_parsed(part, parsed)?;
+                        match (part, variant) {
+                                (_, "default") => Ok(answer),
+                                (Part::Part2, "plus-one") => {
+                                        Ok((answer.parse::<u64>().expect("a sum") + 1).to_string())
+                                }
+                                _ => Err(self.unknown_variant(part, variant))?,
+                        }
+                }
+        }
+}
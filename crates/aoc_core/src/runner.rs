@@ -0,0 +1,142 @@
+//! Isolated, timed execution of a single solve.
+//!
+//! Each run happens on its own thread, with panics caught and a deadline on the result.
+//! A timed out thread cannot be killed; it is detached and left to finish (or not) in the background.
+
+use std::{fmt::{Debug, Display},
+          panic::{self, AssertUnwindSafe},
+          sync::mpsc,
+          thread,
+          time::{Duration, Instant}};
+
+use derive_more::derive::Display;
+use tracing::{self as tea, Level, instrument};
+
+use crate::Result;
+
+/// How an isolated run ended.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum Outcome<T = String> {
+        /// Solved, with the job's value. (e.g. a rendered answer)
+        #[display("OK")]
+        Ok(T),
+        /// The solution returned an error.
+        #[display("ERR")]
+        Err(String),
+        /// The solution panicked. (e.g. a `todo!()`)
+        #[display("PANIC")]
+        Panic(String),
+        /// No result within the time limit.
+        #[display("TIMEOUT")]
+        Timeout,
+}
+impl<T> Outcome<T> {
+        /// Answer or failure detail, for display next to the status.
+        pub fn detail(&self) -> String
+        where
+                T: Display,
+        {
+                match self {
+                        Outcome::Ok(answer) => answer.to_string(),
+                        Outcome::Err(msg) | Outcome::Panic(msg) => msg.clone(),
+                        Outcome::Timeout => String::new(),
+                }
+        }
+
+        pub fn is_ok(&self) -> bool {
+                matches!(self, Outcome::Ok(_))
+        }
+}
+
+/// Result of an isolated run, with wall-clock time. (Time is the limit itself for a timeout.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsolatedRun<T = String> {
+        pub outcome: Outcome<T>,
+        pub elapsed: Duration,
+}
+
+/// Run `job` on its own thread, catching panics and giving up after `timeout`.
+#[instrument(skip(job), ret(level = Level::DEBUG))]
+pub fn run_isolated<T, F>(timeout: Duration, job: F) -> IsolatedRun<T>
+where
+        T: Debug + Send + 'static,
+        F: FnOnce() -> Result<T> + Send + 'static,
+{
+        let (sender, receiver) = mpsc::channel();
+        let start = Instant::now();
+        let spawned = thread::Builder::new()
+                .name("isolated-solve".to_string())
+                .spawn(move || {
+                        let outcome = match panic::catch_unwind(AssertUnwindSafe(job)) {
+                                Ok(Ok(answer)) => Outcome::Ok(answer),
+                                Ok(Err(err)) => Outcome::Err(err.source.to_string()),
+                                Err(payload) => Outcome::Panic(panic_message(payload.as_ref())),
+                        };
+                        // receiver is gone if we already timed out
+                        let _ = sender.send((outcome, start.elapsed()));
+                });
+        if let Err(err) = spawned {
+                return IsolatedRun { outcome: Outcome::Err(err.to_string()), elapsed: start.elapsed() };
+        }
+        match receiver.recv_timeout(timeout) {
+                Ok((outcome, elapsed)) => IsolatedRun { outcome, elapsed },
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                        tea::warn!(?timeout, "isolated run timed out; detaching thread");
+                        IsolatedRun { outcome: Outcome::Timeout, elapsed: timeout }
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => IsolatedRun {
+                        outcome: Outcome::Panic("worker exited without reporting".to_string()),
+                        elapsed: start.elapsed(),
+                },
+        }
+}
+
+/// Pull a readable message out of a panic payload.
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+        if let Some(msg) = payload.downcast_ref::<&str>() {
+                msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+                msg.clone()
+        } else {
+                "non-string panic payload".to_string()
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+        use crate::ErrKindAoc;
+
+        const LIMIT: Duration = Duration::from_secs(5);
+
+        #[test]
+        fn ok_run() {
+                let run = run_isolated(LIMIT, || Ok("42".to_string()));
+                assert_eq!(run.outcome, Outcome::Ok("42".to_string()));
+        }
+
+        #[test]
+        fn err_run() {
+                let run = run_isolated::<String, _>(LIMIT, || Err(ErrKindAoc::UnregisteredDay { day: 26 })?);
+                assert_eq!(run.outcome, Outcome::Err("No solution registered for day 26".to_string()));
+        }
+
+        #[test]
+        fn panic_run() {
+                let run = run_isolated::<String, _>(LIMIT, || todo!());
+                assert_eq!(run.outcome, Outcome::Panic("not yet implemented".to_string()));
+        }
+
+        #[test]
+        fn timeout_run() {
+                let limit = Duration::from_millis(50);
+                let run = run_isolated(limit, || {
+                        thread::sleep(Duration::from_secs(2));
+                        Ok("late".to_string())
+                });
+                assert_eq!(run, IsolatedRun { outcome: Outcome::Timeout, elapsed: limit });
+        }
+}
@@ -0,0 +1,454 @@
+//! The `Solution` trait: the common face each day crate presents to the workspace runner.
+//!
+//! [`Solution`] is the typed trait a day implements.
+//! It has associated types & consts, so it is not object safe; [`DynSolution`] is the erased view used for registries.
+//!
+//! Parsing and solving are separate phases, so each can be timed (and a parse shared between parts).
+//! [`Solution::both`] solves both parts from one parse, and may be overridden to share work between them.
+
+use std::{any::Any,
+          borrow::Cow,
+          fmt::Display,
+          path::Path,
+          time::{Duration, Instant}};
+
+use clap::ValueEnum;
+use derive_more::derive::Display;
+use serde::Deserialize;
+use tracing::{Level, instrument};
+
+use crate::{Answers, Expected, InputPath, InputSource, Result, Variant, input_hash, normalize_input,
+            support::ErrKindAoc, variant::DEFAULT_VARIANT};
+
+/// Error type days are erased to. (Both the boxed and wrapped day errors convert into this.)
+pub type DynError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A day's `Parsed` value, with its type erased.
+pub type DynParsed = Box<dyn Any + Send>;
+
+/// Part 1 or 2 of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ValueEnum, Display)]
+pub enum Part {
+        /// Part 1 of the day's puzzle.
+        #[value(alias = "1", alias = "i", alias = "I", alias = "one")]
+        #[display("1")]
+        Part1,
+        /// Part 2 of the day's puzzle.
+        #[value(alias = "2", alias = "ii", alias = "II", alias = "two")]
+        #[display("2")]
+        Part2,
+}
+
+/// Part(s) to run, as chosen on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum, Display)]
+pub enum Parts {
+        /// Part 1 of the day's puzzle.
+        #[value(alias = "1", alias = "i", alias = "I", alias = "one")]
+        #[display("1")]
+        Part1,
+        /// Part 2 of the day's puzzle.
+        #[value(alias = "2", alias = "ii", alias = "II", alias = "two")]
+        #[display("2")]
+        Part2,
+        /// Both parts, from a single parse.
+        #[value(alias = "b", alias = "12")]
+        #[display("both")]
+        Both,
+}
+impl Parts {
+        /// The one part chosen, unless both were.
+        pub fn single(self) -> Option<Part> {
+                match self {
+                        Parts::Part1 => Some(Part::Part1),
+                        Parts::Part2 => Some(Part::Part2),
+                        Parts::Both => None,
+                }
+        }
+}
+
+/// Data to use as input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ValueEnum, Display, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputKind {
+        /// Use the example input.
+        #[display("example")]
+        Example,
+        /// Use the full problem input.
+        #[display("full")]
+        Full,
+        /// Use a custom input.
+        #[display("custom")]
+        Custom,
+}
+
+/// A single day's puzzle solution.
+///
+/// Implemented on a unit struct in each day crate (e.g. `day06::Day06`).
+pub trait Solution {
+        /// Day of the advent calendar. (1..=25)
+        const DAY: u8;
+        /// Puzzle title, as given on the puzzle page.
+        const TITLE: &'static str;
+        /// Known answers, as the text of the day's `data/answers.toml`. (See [`Answers`].)
+        const ANSWERS: &'static str;
+
+        /// Structured form of the raw input, shared by both parts.
+        type Parsed: Send + 'static;
+        /// Value produced by either part.
+        type Answer: Display;
+        /// The day's own error type.
+        type Error: Into<DynError>;
+
+        /// Source of the given kind of input for a part, if the day has one.
+        fn input(kind: InputKind, part: Part) -> Option<InputSource>;
+
+        /// Turn raw input into the structure both parts work on.
+        fn parse(input: &str) -> std::result::Result<Self::Parsed, Self::Error>;
+
+        /// Solve Part 1.
+        fn part1(parsed: &Self::Parsed) -> std::result::Result<Self::Answer, Self::Error>;
+
+        /// Solve Part 2.
+        fn part2(parsed: &Self::Parsed) -> std::result::Result<Self::Answer, Self::Error>;
+
+        /// Solve both parts; override to reuse Part 1's intermediate results in Part 2.
+        fn both(parsed: &Self::Parsed) -> std::result::Result<(Self::Answer, Self::Answer), Self::Error> {
+                Ok((Self::part1(parsed)?, Self::part2(parsed)?))
+        }
+
+        /// Alternative implementations of a part, by name, to choose between at runtime; the first should be the one
+        /// [`Solution::part1`] / [`Solution::part2`] run. Days with a single implementation list none.
+        fn variants(_part: Part) -> &'static [Variant<Self>] {
+                &[]
+        }
+
+        /// Answer recorded in [`Solution::ANSWERS`] for a part on `input` of the given kind, for tests to check.
+        ///
+        /// Panics if the answers do not parse, none is recorded, or the answer is pinned to another input. (A swapped
+        /// input file fails as such, not as a wrong answer.)
+        fn recorded_answer(part: Part, kind: InputKind, input: &str) -> String {
+                let answers = Answers::from_toml(Self::ANSWERS)
+                        .unwrap_or_else(|err| panic!("Day {:02} answers do not parse: {err}", Self::DAY));
+                match answers.expected(part, kind, Some(&input_hash(&normalize_input(input).0))) {
+                        Expected::Answer(answer) => answer.to_string(),
+                        Expected::Unknown => {
+                                panic!("Day {:02} has no recorded answer to part {part} on {kind} input", Self::DAY)
+                        }
+                        Expected::InputChanged { recorded } => panic!(
+                                "Day {:02}'s {kind} input is not the one its part {part} answer is for ({recorded})",
+                                Self::DAY
+                        ),
+                }
+        }
+}
+
+/// An answer, with the time spent in each phase.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+#[display("{}", answer)]
+pub struct Solved {
+        pub answer:     String,
+        pub parse_time: Duration,
+        pub solve_time: Duration,
+}
+
+/// Answers to both parts from a single parse, with the time spent in each phase. (Solve time covers both parts.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolvedBoth {
+        pub answers:    [String; 2],
+        pub parse_time: Duration,
+        pub solve_time: Duration,
+}
+
+/// Object-safe view of a [`Solution`], so days with different parsed & answer types can share one registry.
+pub trait DynSolution: Sync {
+        /// Day of the advent calendar. (1..=25)
+        fn day(&self) -> u8;
+
+        /// Puzzle title, as given on the puzzle page.
+        fn title(&self) -> &'static str;
+
+        /// Source of the given kind of input for a part, if the day has one.
+        fn input(&self, kind: InputKind, part: Part) -> Option<InputSource>;
+
+        /// Known answers for the day.
+        fn answers(&self) -> Result<Answers>;
+
+        /// Load the given kind of input for a part, reading from `input_dir` if not embedded.
+        fn load_input(&self, kind: InputKind, part: Part, input_dir: &Path) -> Result<Cow<'static, str>> {
+                let day = self.day();
+                self.input(kind, part)
+                        .ok_or(ErrKindAoc::MissingInput { day, kind, part })?
+                        .load_from(input_dir)
+        }
+
+        /// Input named on the command line (file or stdin) if any, else the day's own `kind` of input; with a label.
+        fn select_input(
+                &self,
+                part: Part,
+                path: Option<&InputPath>,
+                kind: InputKind,
+                input_dir: &Path,
+        ) -> (String, Result<Cow<'static, str>>) {
+                match path {
+                        Some(path) => (path.to_string(), path.read().map(Cow::Owned)),
+                        None => (kind.to_string(), self.load_input(kind, part, input_dir)),
+                }
+        }
+
+        /// Where the input [`DynSolution::select_input`] picks is read from: a path, `stdin`, or `embedded`.
+        fn input_origin(&self, part: Part, path: Option<&InputPath>, kind: InputKind, input_dir: &Path) -> String {
+                match (path, self.input(kind, part)) {
+                        (Some(path), _) => path.to_string(),
+                        (None, Some(source)) => source.origin_in(input_dir),
+                        (None, None) => "-".to_string(),
+                }
+        }
+
+        /// Parse `input` into the day's (erased) `Parsed` value, normalizing it first. (See [`normalize_input`].)
+        fn parse(&self, input: &str) -> Result<DynParsed>;
+
+        /// Solve `part` from a value returned by [`DynSolution::parse`], rendering the answer as a string.
+        fn solve_parsed(&self, part: Part, parsed: &DynParsed) -> Result<String>;
+
+        /// Solve both parts from a value returned by [`DynSolution::parse`]. (See [`Solution::both`].)
+        fn solve_both_parsed(&self, parsed: &DynParsed) -> Result<[String; 2]>;
+
+        /// Names of a part's variants, default first. (See [`Solution::variants`].)
+        fn variants(&self, _part: Part) -> Vec<&'static str> {
+                vec![DEFAULT_VARIANT]
+        }
+
+        /// Solve `part` with the named variant, from a value returned by [`DynSolution::parse`].
+        fn solve_variant_parsed(&self, part: Part, variant: &str, parsed: &DynParsed) -> Result<String> {
+                match variant {
+                        DEFAULT_VARIANT => self.solve_parsed(part, parsed),
+                        _ => Err(self.unknown_variant(part, variant))?,
+                }
+        }
+
+        /// Error for a variant name the part does not have, listing those it does.
+        fn unknown_variant(&self, part: Part, variant: &str) -> ErrKindAoc {
+                ErrKindAoc::UnknownVariant {
+                        day: self.day(),
+                        part,
+                        variant: variant.into(),
+                        known: self.variants(part).join(", ").into(),
+                }
+        }
+
+        /// Parse `input` and solve `part`, timing each phase.
+        #[instrument(skip(self, input), fields(day = self.day()), ret(level = Level::DEBUG))]
+        fn solve(&self, part: Part, input: &str) -> Result<Solved> {
+                let start = Instant::now();
+                let parsed = self.parse(input)?;
+                let parse_time = start.elapsed();
+                let start = Instant::now();
+                let answer = self.solve_parsed(part, &parsed)?;
+                let solve_time = start.elapsed();
+                Ok(Solved { answer, parse_time, solve_time })
+        }
+
+        /// Parse `input` once and solve both parts, timing each phase.
+        #[instrument(skip(self, input), fields(day = self.day()), ret(level = Level::DEBUG))]
+        fn solve_both(&self, input: &str) -> Result<SolvedBoth> {
+                let start = Instant::now();
+                let parsed = self.parse(input)?;
+                let parse_time = start.elapsed();
+                let start = Instant::now();
+                let answers = self.solve_both_parsed(&parsed)?;
+                let solve_time = start.elapsed();
+                Ok(SolvedBoth { answers, parse_time, solve_time })
+        }
+}
+impl<S> DynSolution for S
+where
+        S: Solution + Sync + 'static,
+{
+        fn day(&self) -> u8 {
+                S::DAY
+        }
+
+        fn title(&self) -> &'static str {
+                S::TITLE
+        }
+
+        fn input(&self, kind: InputKind, part: Part) -> Option<InputSource> {
+                S::input(kind, part)
+        }
+
+        fn answers(&self) -> Result<Answers> {
+                Ok(Answers::from_toml(S::ANSWERS)
+                        .map_err(|source| ErrKindAoc::Answers { day: S::DAY, source: Box::new(source) })?)
+        }
+
+        #[instrument(skip_all, fields(day = S::DAY))]
+        fn parse(&self, input: &str) -> Result<DynParsed> {
+                let parsed = S::parse(&normalize_input(input).0)
+                        .map_err(|e| ErrKindAoc::Parse { day: S::DAY, source: e.into() })?;
+                Ok(Box::new(parsed))
+        }
+
+        #[instrument(skip(self, parsed), fields(day = S::DAY), ret(level = Level::DEBUG))]
+        fn solve_parsed(&self, part: Part, parsed: &DynParsed) -> Result<String> {
+                let day = S::DAY;
+                let parsed = parsed
+                        .downcast_ref::<S::Parsed>()
+                        .ok_or(ErrKindAoc::ParsedTypeMismatch { day })?;
+                let answer = match part {
+                        Part::Part1 => S::part1(parsed),
+                        Part::Part2 => S::part2(parsed),
+                }
+                .map_err(|e| ErrKindAoc::Day { day, source: e.into() })?;
+                Ok(answer.to_string())
+        }
+
+        #[instrument(skip_all, fields(day = S::DAY), ret(level = Level::DEBUG))]
+        fn solve_both_parsed(&self, parsed: &DynParsed) -> Result<[String; 2]> {
+                let day = S::DAY;
+                let parsed = parsed
+                        .downcast_ref::<S::Parsed>()
+                        .ok_or(ErrKindAoc::ParsedTypeMismatch { day })?;
+                let (part1, part2) = S::both(parsed).map_err(|e| ErrKindAoc::Day { day, source: e.into() })?;
+                Ok([part1.to_string(), part2.to_string()])
+        }
+
+        fn variants(&self, part: Part) -> Vec<&'static str> {
+                match S::variants(part) {
+                        [] => vec![DEFAULT_VARIANT],
+                        variants => variants.iter().map(|variant| variant.name).collect(),
+                }
+        }
+
+        #[instrument(skip(self, parsed), fields(day = S::DAY), ret(level = Level::DEBUG))]
+        fn solve_variant_parsed(&self, part: Part, variant: &str, parsed: &DynParsed) -> Result<String> {
+                let day = S::DAY;
+                let solve = match S::variants(part) {
+                        [] if variant == DEFAULT_VARIANT => return self.solve_parsed(part, parsed),
+                        variants => {
+                                variants.iter()
+                                        .find(|known| known.name == variant)
+                                        .ok_or_else(|| self.unknown_variant(part, variant))?
+                                        .solve
+                        }
+                };
+                let parsed = parsed
+                        .downcast_ref::<S::Parsed>()
+                        .ok_or(ErrKindAoc::ParsedTypeMismatch { day })?;
+                let answer = solve(parsed).map_err(|e| ErrKindAoc::Day { day, source: e.into() })?;
+                Ok(answer.to_string())
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+        use test_log::test;
+
+        use super::*;
+
+        /// Sums (part 1) or multiplies (part 2) whitespace separated numbers.
+        struct Toy;
+        impl Solution for Toy {
+                type Answer = u64;
+                type Error = DynError;
+                type Parsed = Vec<u64>;
+
+                const ANSWERS: &'static str = "[part1]\nexample = \"6\"\n";
+                const DAY: u8 = 25;
+                const TITLE: &'static str = "Toy Problem";
+
+                fn input(kind: InputKind, _part: Part) -> Option<InputSource> {
+                        match kind {
+                                InputKind::Example => Some(InputSource::Embedded("1 2 3")),
+                                _ => None,
+                        }
+                }
+
+                fn parse(input: &str) -> std::result::Result<Self::Parsed, Self::Error> {
+                        Ok(input.split_whitespace()
+                                .map(str::parse)
+                                .collect::<std::result::Result<_, _>>()?)
+                }
+
+                fn part1(parsed: &Self::Parsed) -> std::result::Result<Self::Answer, Self::Error> {
+                        Ok(parsed.iter().sum())
+                }
+
+                fn part2(parsed: &Self::Parsed) -> std::result::Result<Self::Answer, Self::Error> {
+                        Ok(parsed.iter().product())
+                }
+
+                fn variants(part: Part) -> &'static [Variant<Self>] {
+                        match part {
+                                Part::Part1 => &[Variant { name: "sum", solve: Self::part1 }, Variant {
+                                        name:  "sum-rev",
+                                        solve: |parsed| Ok(parsed.iter().rev().sum()),
+                                }],
+                                Part::Part2 => &[],
+                        }
+                }
+        }
+
+        #[test]
+        fn dyn_solution_dispatch() -> Result<()> {
+                let toy: &dyn DynSolution = &Toy;
+                assert_eq!((toy.day(), toy.title()), (25, "Toy Problem"));
+                let input = toy
+                        .input(InputKind::Example, Part::Part1)
+                        .expect("toy has example input")
+                        .load()?;
+                assert_eq!(toy.solve(Part::Part1, &input)?.answer, "6");
+                assert_eq!(toy.solve(Part::Part2, "2 3 4")?.answer, "24");
+                assert_eq!(toy.input(InputKind::Full, Part::Part1), None);
+                assert_eq!(toy.answers()?.get(Part::Part1, InputKind::Example), Some("6"));
+                assert_eq!(Toy::recorded_answer(Part::Part1, InputKind::Example, &input), "6");
+                Ok(())
+        }
+
+        #[test]
+        fn dyn_solution_variants() -> Result<()> {
+                let toy: &dyn DynSolution = &Toy;
+                assert_eq!(toy.variants(Part::Part1), ["sum", "sum-rev"]);
+                assert_eq!(toy.variants(Part::Part2), [DEFAULT_VARIANT]);
+                let parsed = toy.parse("2 3 4")?;
+                assert_eq!(toy.solve_variant_parsed(Part::Part1, "sum-rev", &parsed)?, "9");
+                assert_eq!(toy.solve_variant_parsed(Part::Part2, DEFAULT_VARIANT, &parsed)?, "24");
+                let err = toy
+                        .solve_variant_parsed(Part::Part1, DEFAULT_VARIANT, &parsed)
+                        .expect_err("not listed");
+                assert!(matches!(err.source, ErrKindAoc::UnknownVariant { day: 25, .. }));
+                Ok(())
+        }
+
+        #[test]
+        fn dyn_solution_day_error() {
+                let toy: &dyn DynSolution = &Toy;
+                let err = toy.solve(Part::Part1, "1 two 3").expect_err("non-numeric input");
+                assert!(matches!(err.source, ErrKindAoc::Parse { day: 25, .. }));
+        }
+
+        #[test]
+        fn dyn_solution_shared_parse() -> Result<()> {
+                let toy: &dyn DynSolution = &Toy;
+                let parsed = toy.parse("2 3 4")?;
+                assert_eq!(toy.solve_parsed(Part::Part1, &parsed)?, "9");
+                assert_eq!(toy.solve_parsed(Part::Part2, &parsed)?, "24");
+                Ok(())
+        }
+
+        #[test]
+        fn dyn_solution_both() -> Result<()> {
+                let toy: &dyn DynSolution = &Toy;
+                assert_eq!(toy.solve_both("2 3 4")?.answers, ["9", "24"]);
+                assert!(Parts::Both.single().is_none());
+                Ok(())
+        }
+
+        #[test]
+        fn dyn_solution_parsed_type_mismatch() {
+                let toy: &dyn DynSolution = &Toy;
+                let wrong: DynParsed = Box::new("not a Vec<u64>");
+                let err = toy.solve_parsed(Part::Part1, &wrong).expect_err("wrong parsed type");
+                assert!(matches!(err.source, ErrKindAoc::ParsedTypeMismatch { day: 25 }));
+        }
+}
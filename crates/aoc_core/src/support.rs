@@ -0,0 +1,10 @@
+//! Support code for the Advent of Code 2024 workspace.
+
+pub mod error;
+mod subscriber;
+
+pub use error::{ErrKindAoc, ErrWrapperAoc};
+pub use subscriber::activate_global_default_tracing_subscriber;
+
+pub type Result<T> = std::result::Result<T, ErrWrapperAoc>;
+pub type Error = ErrWrapperAoc;
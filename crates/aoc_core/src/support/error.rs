@@ -0,0 +1,162 @@
+//! Error & Result type for the Advent of Code 2024 workspace.
+//!
+//! ## Utility reference
+//! For adding backtrace to errors:
+//! `#![feature(error_generic_member_access)]`
+//! `use std::backtrace;`
+
+use std::io;
+
+use derive_more::{Display, Error, From};
+use tracing::{instrument, subscriber::SetGlobalDefaultError};
+
+use crate::DynError;
+
+#[derive(Debug, Display, From, Error)]
+pub enum ErrKindAoc {
+        // `custom` errors //
+        #[from(ignore)]
+        #[display("Day {:02} error: {}", day, source)]
+        Day { day: u8, source: DynError },
+
+        #[from(ignore)]
+        #[display("Day {:02} could not parse input: {}", day, source)]
+        Parse { day: u8, source: DynError },
+
+        #[from(ignore)]
+        #[display("No solution registered for day {}", day)]
+        UnregisteredDay { day: u8 },
+
+        #[from(ignore)]
+        #[display("Day {:02} has no {} input for part {}", day, kind, part)]
+        MissingInput { day: u8, kind: crate::InputKind, part: crate::Part },
+
+        #[from(ignore)]
+        #[display(
+                "Input file not found: {} (place the puzzle input there, or set the input directory)",
+                path.display()
+        )]
+        InputFileNotFound { path: std::path::PathBuf },
+
+        #[from(ignore)]
+        #[display("Error reading input file {}: {}", path.display(), source)]
+        InputFileRead { path: std::path::PathBuf, source: io::Error },
+
+        #[from(ignore)]
+        #[display("Input {} is encrypted; set {} to the team key to read it", path.display(), crate::INPUT_KEY_ENV)]
+        MissingInputKey { path: std::path::PathBuf },
+
+        #[from(ignore)]
+        #[display("{} is not set (`aoc keygen` makes a team key)", crate::INPUT_KEY_ENV)]
+        NoInputKey,
+
+        #[from(ignore)]
+        #[display("{} is not a key: expected 64 hex digits", crate::INPUT_KEY_ENV)]
+        InvalidInputKey,
+
+        #[from(ignore)]
+        #[display("Could not decrypt {} (wrong key, or not an encrypted input)", path.display())]
+        Decrypt { path: std::path::PathBuf },
+
+        #[from(ignore)]
+        #[display("{} differs from its encrypted copy (use --force to overwrite it)", path.display())]
+        PlainInputDiffers { path: std::path::PathBuf },
+
+        #[from(ignore)]
+        #[display("Day {:02} answers file is malformed: {}", day, source)]
+        Answers { day: u8, source: Box<toml::de::Error> },
+
+        #[from(ignore)]
+        #[display("Error reading config file {}: {}", path.display(), source)]
+        ConfigRead { path: std::path::PathBuf, source: io::Error },
+
+        #[from(ignore)]
+        #[display("Config file {} is malformed: {}", path.display(), source)]
+        Config { path: std::path::PathBuf, source: Box<toml::de::Error> },
+
+        #[from(ignore)]
+        #[display("Config option `{}` has the wrong type: {}", key, source)]
+        ConfigOption { key: String, source: Box<toml::de::Error> },
+
+        #[from(ignore)]
+        #[display("Day {:02} part {} has no variant `{}` (known: {})", day, part, variant, known)]
+        UnknownVariant { day: u8, part: crate::Part, variant: Box<str>, known: Box<str> },
+
+        #[from(ignore)]
+        #[display("Day {:02} was handed a parsed value of another type", day)]
+        ParsedTypeMismatch { day: u8 },
+
+        #[from(ignore)]
+        #[display("{} expected answers given, for {} part(s)", given, parts)]
+        ExpectedCount { given: usize, parts: usize },
+
+        #[from(ignore)]
+        #[display("{} recorded answer(s) not reproduced", failed)]
+        VerifyFailed { failed: usize },
+
+        // `packed` errors //
+        #[display("CLI parsing library error: {}", source)]
+        Clap { source: clap::Error },
+
+        #[display("Error with tracing_subscriber::EnvFilter parsing env directive: {}", source)]
+        EnvError { source: tracing_subscriber::filter::FromEnvError },
+
+        #[display("io error: {}", source)]
+        Io { source: io::Error },
+
+        #[display("JSON error: {}", source)]
+        Json { source: serde_json::Error },
+
+        #[display("Error setting tracing subscriber default: {}", source)]
+        TracingSubscriber { source: SetGlobalDefaultError },
+
+        // `other` errors //
+        #[from(ignore)] // use `make_dyn_error` instead; would conflict with auto-derives
+        #[display("Uncategorized Error (dyn error object): {}", source)]
+        OtherDynError { source: DynError },
+
+        #[display(r#"Uncategorized string err: "{}""#, source_string)]
+        OtherStringError { source_string: String },
+}
+impl ErrKindAoc {
+        #[instrument(skip_all)]
+        pub fn make_dyn_error<E>(error: E) -> Self
+        where
+                E: Into<DynError>,
+        {
+                Self::OtherDynError { source: error.into() }
+        }
+}
+
+#[derive(Display, Error, From)]
+#[display(
+        "error: {:#}\n\n\nspantrace capture: {:?}\n\n\nspantrace: {:#}",
+        source,
+        spantrace.status(),
+        spantrace,
+)]
+pub struct ErrWrapperAoc {
+        pub source:    ErrKindAoc,
+        pub spantrace: tracing_error::SpanTrace,
+        // backtrace: backtrace::Backtrace,
+}
+// Using custom display as debug so we can get SpanTrace auto printed.
+impl std::fmt::Debug for ErrWrapperAoc {
+        #[instrument(skip_all)]
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self)
+        }
+}
+impl<T> From<T> for ErrWrapperAoc
+where
+        T: Into<ErrKindAoc>,
+{
+        #[instrument(skip_all)]
+        fn from(error: T) -> Self {
+                Self {
+                        source:    error.into(),
+                        spantrace: tracing_error::SpanTrace::capture(),
+                        // backtrace: backtrace::Backtrace::capture(),
+                }
+        }
+}
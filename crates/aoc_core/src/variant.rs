@@ -0,0 +1,39 @@
+//! Named alternative implementations of a part, chosen at runtime (`--variant`) or cross-checked (`--all-variants`).
+//!
+//! A day lists its variants with [`Solution::variants`](crate::Solution::variants); days with a single implementation
+//! list none, and have only [`DEFAULT_VARIANT`].
+
+use clap::Args;
+
+use crate::Solution;
+
+/// Name of the only variant of a part that lists none.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// A part's solver, as [`Solution::part1`] / [`Solution::part2`].
+pub type SolveFn<S> =
+        fn(&<S as Solution>::Parsed) -> std::result::Result<<S as Solution>::Answer, <S as Solution>::Error>;
+
+/// One implementation of a part.
+pub struct Variant<S: Solution + ?Sized> {
+        /// Name to choose it by. (kebab-case)
+        pub name:  &'static str,
+        pub solve: SolveFn<S>,
+}
+
+/// Variant(s) to run, as chosen on the command line.
+#[derive(Args, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Variants {
+        /// Implementation of the part to run, by name. (`--all-variants` lists them)
+        #[arg(long, value_name = "NAME", conflicts_with = "all_variants")]
+        pub variant:      Option<String>,
+        /// Run every implementation of the part on one parse; exits with a distinct code if their answers disagree.
+        #[arg(long)]
+        pub all_variants: bool,
+}
+impl Variants {
+        /// Neither a variant nor all variants were chosen.
+        pub fn is_default(&self) -> bool {
+                self.variant.is_none() && !self.all_variants
+        }
+}
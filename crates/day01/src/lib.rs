@@ -4,11 +4,53 @@ mod part1_lib;
 mod part2_lib;
 mod support;
 
-pub use part1_lib::process_part1;
-pub use part2_lib::process_part2;
+use aoc_core::{InputKind, InputSource, Part, Solution};
+pub use part1_lib::{parse_input1, process_part1, solve_part1};
+pub use part2_lib::{process_part2, solve_part2};
 pub use support::{Error, Result, generate_tracing_subscriber};
 
-pub const FINAL_INPUT_1: &str = include_str!("../data/final_input1.txt");
-pub const FINAL_INPUT_2: &str = include_str!("../data/final_input2.txt");
 pub const EXAMPLE_INPUT_1: &str = include_str!("../data/example_input1.txt");
 pub const EXAMPLE_INPUT_2: &str = include_str!("../data/example_input2.txt");
+/// Full puzzle input (both parts): read at runtime from the input directory, or embedded with the `embed-inputs` feature.
+#[cfg(not(feature = "embed-inputs"))]
+pub const FINAL_INPUT_1: InputSource = InputSource::File { day: 1, name: "final_input.txt" };
+#[cfg(feature = "embed-inputs")]
+pub const FINAL_INPUT_1: InputSource =
+        InputSource::Embedded(include_str!("../../../data/no-sync/day01/final_input.txt"));
+pub const FINAL_INPUT_2: InputSource = FINAL_INPUT_1;
+pub const ANSWERS: &str = include_str!("../data/answers.toml");
+
+/// Day01, as registered with the workspace `aoc` runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Day01;
+impl Solution for Day01 {
+        type Answer = u64;
+        type Error = Error;
+        type Parsed = (Vec<u64>, Vec<u64>);
+
+        const ANSWERS: &'static str = ANSWERS;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Historian Hysteria";
+
+        fn input(kind: InputKind, part: Part) -> Option<InputSource> {
+                match (kind, part) {
+                        (InputKind::Example, Part::Part1) => Some(InputSource::Embedded(EXAMPLE_INPUT_1)),
+                        (InputKind::Example, Part::Part2) => Some(InputSource::Embedded(EXAMPLE_INPUT_2)),
+                        (InputKind::Full, Part::Part1) => Some(FINAL_INPUT_1),
+                        (InputKind::Full, Part::Part2) => Some(FINAL_INPUT_2),
+                        (InputKind::Custom, _) => None,
+                }
+        }
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+                parse_input1(input)
+        }
+
+        fn part1((left, right): &Self::Parsed) -> Result<Self::Answer> {
+                Ok(solve_part1(left, right))
+        }
+
+        fn part2((left, right): &Self::Parsed) -> Result<Self::Answer> {
+                Ok(solve_part2(left, right))
+        }
+}
@@ -2,7 +2,7 @@
 //! `bin > part1_bin.rs` will run this code along with content of `input1.txt`
 
 mod parse1;
-use parse1::parse_input1;
+pub use parse1::parse_input1;
 use tracing::{self as tea, instrument};
 
 #[expect(unused)]
@@ -11,14 +11,21 @@ use crate::{EXAMPLE_INPUT_1, FINAL_INPUT_1, support::Result};
 #[instrument(skip(input))]
 pub fn process_part1(input: &str) -> Result<u64> {
         tea::trace!(%input, "raw");
-        let (mut left, mut right) = parse_input1(input)?;
+        let (left, right) = parse_input1(input)?;
+        Ok(solve_part1(&left, &right))
+}
+
+/// Sum of distances between the sorted left & right lists.
+#[instrument(skip_all)]
+pub fn solve_part1(left: &[u64], right: &[u64]) -> u64 {
+        let (mut left, mut right) = (left.to_vec(), right.to_vec());
         left.sort_unstable();
         right.sort_unstable();
         tea::trace!(?left, ?right, "sorted");
         let diff_iter = left.iter().zip(right.iter()).map(|(&l, &r)| l.abs_diff(r));
         tea::trace!(diff_iter = ?diff_iter.clone().sum::<u64>(), "cloned sum");
 
-        Ok(diff_iter.sum())
+        diff_iter.sum()
 }
 
 // #[cfg(test)]
@@ -14,6 +14,12 @@ use crate::{EXAMPLE_INPUT_2, FINAL_INPUT_2, support::Result};
 pub fn process_part2(input: &str) -> Result<u64> {
         tea::trace!(%input);
         let (left, right) = parse_input2(input)?;
+        Ok(solve_part2(&left, &right))
+}
+
+/// Similarity score: each left value, times its frequency in both lists.
+#[instrument(skip_all)]
+pub fn solve_part2(left: &[u64], right: &[u64]) -> u64 {
         tea::trace!(?left, ?right);
         let left_fcount: HashMap<u64, u64> = freq_count(left);
         let right_fcount: HashMap<u64, u64> = freq_count(right);
@@ -26,12 +32,12 @@ pub fn process_part2(input: &str) -> Result<u64> {
         }
         tea::info!(?total);
 
-        Ok(total)
+        total
 }
 
-fn freq_count(input: Vec<u64>) -> HashMap<u64, u64> {
+fn freq_count(input: &[u64]) -> HashMap<u64, u64> {
         let mut freq_count: HashMap<u64, u64> = HashMap::with_capacity(input.len());
-        for i in input {
+        for &i in input {
                 let count = freq_count.entry(i).or_insert(0);
                 *count += 1;
         }
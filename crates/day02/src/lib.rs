@@ -4,13 +4,20 @@ mod part1_lib;
 mod part2_lib;
 mod support;
 
-pub use part1_lib::process_part1;
-pub use part2_lib::process_part2;
+use aoc_core::{InputKind, InputSource, Part, Solution};
+pub use parse::{LineReport, parse_input};
+pub use part1_lib::{process_part1, solve_part1};
+pub use part2_lib::{process_part2, solve_part2};
 pub use support::{Error, Result, generate_tracing_subscriber};
 
-pub const FINAL_INPUT: &str = include_str!("../data/final_input.txt");
 pub const EXAMPLE_INPUT: &str = include_str!("../data/example_input.txt");
 pub const CUSTOM_INPUT: &str = include_str!("../data/custom_input.txt");
+/// Full puzzle input: read at runtime from the input directory, or embedded with the `embed-inputs` feature.
+#[cfg(not(feature = "embed-inputs"))]
+pub const FINAL_INPUT: InputSource = InputSource::File { day: 2, name: "final_input.txt" };
+#[cfg(feature = "embed-inputs")]
+pub const FINAL_INPUT: InputSource = InputSource::Embedded(include_str!("../../../data/no-sync/day02/final_input.txt"));
+pub const ANSWERS: &str = include_str!("../data/answers.toml");
 
 mod parse {
         use derive_more::derive::{Add, Constructor, Deref, DerefMut, From, Into, Sum};
@@ -48,3 +55,36 @@ mod parse {
                 Ok(out)
         }
 }
+
+/// Day02, as registered with the workspace `aoc` runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Day02;
+impl Solution for Day02 {
+        type Answer = u64;
+        type Error = Error;
+        type Parsed = Vec<LineReport>;
+
+        const ANSWERS: &'static str = ANSWERS;
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Red-Nosed Reports";
+
+        fn input(kind: InputKind, _part: Part) -> Option<InputSource> {
+                match kind {
+                        InputKind::Example => Some(InputSource::Embedded(EXAMPLE_INPUT)),
+                        InputKind::Full => Some(FINAL_INPUT),
+                        InputKind::Custom => Some(InputSource::Embedded(CUSTOM_INPUT)),
+                }
+        }
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+                parse_input(input)
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Self::Answer> {
+                solve_part1(parsed)
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Self::Answer> {
+                solve_part2(parsed)
+        }
+}
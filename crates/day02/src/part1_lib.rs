@@ -4,12 +4,18 @@
 use tracing::{self as tea, Level, instrument};
 
 use crate::{Result,
-            parse::{Difference, ReportStatus, parse_input}};
+            parse::{Difference, LineReport, ReportStatus, parse_input}};
 
 #[instrument(skip_all, ret(level = Level::INFO))]
 pub fn process_part1(input: &str) -> Result<u64> {
         tea::trace!(%input);
         let line_reports = parse_input(input)?;
+        solve_part1(&line_reports)
+}
+
+/// Count of reports that are safe as-is.
+#[instrument(skip_all, ret(level = Level::INFO))]
+pub fn solve_part1(line_reports: &[LineReport]) -> Result<u64> {
         let safe_lines_count = line_reports
                 .iter()
                 .map(|line| {
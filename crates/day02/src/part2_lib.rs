@@ -10,9 +10,12 @@ use crate::{Result,
 pub fn process_part2(raw_input: &str) -> Result<u64> {
         tea::trace!(%raw_input);
         let line_reports = parse_input(raw_input)?;
-        process_parsed_2(line_reports)
+        solve_part2(&line_reports)
 }
-fn process_parsed_2(line_reports: Vec<LineReport>) -> Result<u64> {
+
+/// Count of reports that are safe, allowing a single level to be dropped.
+#[instrument(skip_all, ret(level = Level::INFO))]
+pub fn solve_part2(line_reports: &[LineReport]) -> Result<u64> {
         let safe_line_reports = line_reports
                 .iter()
                 .map(|line| {
@@ -266,7 +269,7 @@ mod tests {
                 let len = len as usize;
                 let good_seq = generate_good_sequence(len, sign);
                 tea::warn!(?good_seq);
-                let val = process_parsed_2(good_seq.clone());
+                let val = solve_part2(&good_seq);
                 match val {
                         Ok(1) => {
                                 tea::info!(?val, ?good_seq);
@@ -25,6 +25,19 @@ impl MulPair {
         }
 }
 
+/// Both readings of the input: every `mul` (Part 1), and only those enabled by `do()`/`don't()` (Part 2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instructions {
+        pub all:     Vec<MulPair>,
+        pub enabled: Vec<MulPair>,
+}
+
+/// Parse txt input once for both parts.
+#[instrument(skip_all, ret(level = Level::TRACE))]
+pub fn parse_input(raw_input: &str) -> Result<Instructions> {
+        Ok(Instructions { all: parse_input_1(raw_input)?, enabled: parse_input_2(raw_input)? })
+}
+
 /// Parse txt input: extracting number pairs from text.
 /// No attention is paid to individual lines.
 ///
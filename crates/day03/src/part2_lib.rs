@@ -9,12 +9,12 @@ use crate::{MulPair, Result, parse::parse_input_2};
 pub fn process_part2(input: &str) -> Result<u64> {
         tea::trace!(%input);
         let parsed_input = parse_input_2(input)?;
-        Ok(calculate_solution(parsed_input))
+        Ok(solve_part2(&parsed_input))
 }
 
 /// Process solution on prased input.
 #[instrument(skip_all, ret(level = Level::DEBUG))]
-fn calculate_solution(pairs_vec: Vec<MulPair>) -> u64 {
+pub fn solve_part2(pairs_vec: &[MulPair]) -> u64 {
         pairs_vec
                 .iter()
                 .map(|pair| pair.self_multiply())
@@ -24,27 +24,29 @@ fn calculate_solution(pairs_vec: Vec<MulPair>) -> u64 {
 
 #[cfg(test)]
 mod tests {
+        use aoc_core::{InputKind, Part, Solution as _};
         use test_log::test;
         use tracing::instrument;
 
         use super::*;
-        use crate::{EXAMPLE_INPUT_2, FINAL_INPUT};
+        use crate::{Day03, EXAMPLE_INPUT_2, FINAL_INPUT};
 
         #[test]
         #[instrument]
         fn part2_example_input_test() -> Result<()> {
                 let input = EXAMPLE_INPUT_2;
-                let expected = 48;
-                assert_eq!(process_part2(input)?, expected);
+                let expected = Day03::recorded_answer(Part::Part2, InputKind::Example, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
                 Ok(())
         }
 
         #[test]
+        #[ignore = "needs the private full input; run with `just test-full`"]
         #[instrument]
         fn part2_final_input_test() -> Result<()> {
-                let input = FINAL_INPUT;
-                let expected = 90_044_227;
-                assert_eq!(process_part2(input)?, expected);
+                let input = &FINAL_INPUT.load()?;
+                let expected = Day03::recorded_answer(Part::Part2, InputKind::Full, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
                 Ok(())
         }
 }
@@ -10,14 +10,16 @@ use tracing_tree::HierarchicalLayer;
 type SpecificLayered =
         Layered<EnvFilter, Layered<HierarchicalLayer<fn() -> Stderr, tracing_tree::time::Uptime>, Registry>>;
 
+const DEFAULT_LOGGING_LEVEL: LevelFilter = LevelFilter::WARN;
+
 /// Generates a tracing_subcsriber.  (Convenience function.)
-pub fn generate_tracing_subscriber() -> SpecificLayered {
+pub fn generate_tracing_subscriber(env_default_level: Option<LevelFilter>) -> SpecificLayered {
         let tree_layer = tracing_tree::HierarchicalLayer::new(3)
                 .with_timer(tracing_tree::time::Uptime::default())
                 // .with_span_modes(true)
                 .with_indent_lines(true);
         let envfilter_layer = EnvFilter::builder()
-                .with_default_directive(LevelFilter::WARN.into())
+                .with_default_directive(env_default_level.unwrap_or(DEFAULT_LOGGING_LEVEL).into())
                 .from_env_lossy();
         Registry::default().with(tree_layer).with(envfilter_layer)
 }
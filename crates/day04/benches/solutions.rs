@@ -0,0 +1,27 @@
+//! Benchmarks of Day04's parsing & solutions, on its example & full inputs. (`cargo bench -p day04`)
+//!
+//! Full inputs are read from the input directory; a part whose full input is missing is only benchmarked on its
+//! example. `cargo xtask bench` keeps the results as JSON baselines, and reports regressions between runs.
+
+use aoc_core::{BenchInput, Part, bench_inputs};
+use day04::{Day04, parse_input, process_part1, process_part2};
+use divan::{Bencher, black_box};
+
+fn main() {
+        divan::main();
+}
+
+#[divan::bench(args = bench_inputs::<Day04>(Part::Part1))]
+fn parse(bencher: Bencher, input: &BenchInput) {
+        bencher.bench(|| parse_input(black_box(&input.text)));
+}
+
+#[divan::bench(args = bench_inputs::<Day04>(Part::Part1))]
+fn part1(bencher: Bencher, input: &BenchInput) {
+        bencher.bench(|| process_part1(black_box(&input.text)));
+}
+
+#[divan::bench(args = bench_inputs::<Day04>(Part::Part2))]
+fn part2(bencher: Bencher, input: &BenchInput) {
+        bencher.bench(|| process_part2(black_box(&input.text)));
+}
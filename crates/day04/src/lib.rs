@@ -5,12 +5,52 @@ mod part1_lib;
 mod part2_lib;
 mod support;
 
-pub use parse::parse_input_1;
-pub use part1_lib::process_part1;
-pub use part2_lib::process_part2;
+use aoc_core::{InputKind, InputSource, Part, Solution};
+pub use parse::{CWordPuzzle, parse_input, parse_input_1};
+pub use part1_lib::{process_part1, solve_part1};
+pub use part2_lib::{process_part2, solve_part2};
 pub use support::{ErrKindDay04, Error, Result, generate_tracing_subscriber};
 
-pub const FINAL_INPUT: &str = include_str!("../data/final_input.txt");
 pub const EXAMPLE_INPUT_1: &str = include_str!("../data/example_input_1.txt");
 pub const EXAMPLE_INPUT_2: &str = include_str!("../data/example_input_2.txt");
 pub const CUSTOM_INPUT: &str = include_str!("../data/custom_input.txt");
+/// Full puzzle input: read at runtime from the input directory, or embedded with the `embed-inputs` feature.
+#[cfg(not(feature = "embed-inputs"))]
+pub const FINAL_INPUT: InputSource = InputSource::File { day: 4, name: "final_input.txt" };
+#[cfg(feature = "embed-inputs")]
+pub const FINAL_INPUT: InputSource = InputSource::Embedded(include_str!("../../../data/no-sync/day04/final_input.txt"));
+pub const ANSWERS: &str = include_str!("../data/answers.toml");
+
+/// Day04, as registered with the workspace `aoc` runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Day04;
+impl Solution for Day04 {
+        type Answer = u64;
+        type Error = ErrKindDay04;
+        type Parsed = CWordPuzzle;
+
+        const ANSWERS: &'static str = ANSWERS;
+        const DAY: u8 = 4;
+        const TITLE: &'static str = "Ceres Search";
+
+        fn input(kind: InputKind, part: Part) -> Option<InputSource> {
+                match (kind, part) {
+                        (InputKind::Example, Part::Part1) => Some(InputSource::Embedded(EXAMPLE_INPUT_1)),
+                        (InputKind::Example, Part::Part2) => Some(InputSource::Embedded(EXAMPLE_INPUT_2)),
+                        (InputKind::Full, _) => Some(FINAL_INPUT),
+                        (InputKind::Custom, _) => Some(InputSource::Embedded(CUSTOM_INPUT)),
+                }
+        }
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+                parse_input(input)
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Self::Answer> {
+                Ok(solve_part1(parsed))
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Self::Answer> {
+                solve_part2(parsed)
+        }
+}
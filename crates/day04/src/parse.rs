@@ -1,6 +1,6 @@
 //! Raw-input parsing code for Day04 of Advent of Code 2024.
 
-use derive_more::derive::{Constructor, FromStr, Index, IntoIterator};
+use derive_more::derive::{Constructor, Display, FromStr, Index, IntoIterator};
 use tracing::{self as tea, Level, instrument};
 
 use crate::{ErrKindDay04, Result};
@@ -11,6 +11,16 @@ pub fn parse_input_1(raw_input: &str) -> Result<CWordPuzzle> {
         CWordPuzzle::from_str(raw_input)
 }
 
+/// Parse txt input once for both parts.
+/// Unlike [`parse_input_1`] this rejects input without any lines, rather than panicking on it.
+#[instrument(skip_all, ret(level = Level::TRACE))]
+pub fn parse_input(raw_input: &str) -> Result<CWordPuzzle> {
+        if raw_input.lines().next().is_none() {
+                Err(ErrKindDay04::NoInputLines { source_input: raw_input.to_string() })?;
+        }
+        CWordPuzzle::from_str(raw_input)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CWordPuzzle {
         pub horizontal_view: Vec<CWordLine>,
@@ -30,11 +40,22 @@ impl CWordPuzzle {
                 let horizontal_view: Vec<CWordLine> =
                         str_input.lines().map(CWordLine::from_str).collect::<Result<_>>()?;
 
-                // let num_chars = str_input.chars().count(); // len would work for ascii assumption
-                let num_chars = str_input.len(); // assuming ascii chars
+                // from the first row, so any line endings (or a missing final newline) are not counted
                 let num_rows = horizontal_view.len();
-                let num_cols = (num_chars / num_rows) - 1; // -1 for newline
-                tea::info!(num_chars, num_rows, num_cols);
+                let num_cols = horizontal_view.first().map_or(0, |line| line.chars.len());
+                tea::info!(num_rows, num_cols);
+                // every view below indexes the grid by the first row's length
+                if let Some((row, line)) = horizontal_view
+                        .iter()
+                        .enumerate()
+                        .find(|(_, line)| line.chars.len() != num_cols)
+                {
+                        Err(ErrKindDay04::RaggedRow {
+                                row:      row + 1,
+                                len:      line.chars.len(),
+                                expected: num_cols,
+                        })?;
+                }
 
                 // transpose of horizontal
                 let mut vertical_view: Vec<CWordLine> = Vec::new();
@@ -156,15 +177,31 @@ impl CWordPuzzle {
         pub fn canonical_view(&self) -> Vec<CWordLine> {
                 self.horizontal_view.clone()
         }
+
+        /// Renders the puzzle back to text: one newline terminated line per row.
+        #[instrument(level = Level::TRACE, skip_all)]
+        pub fn to_text(&self) -> String {
+                self.horizontal_view
+                        .iter()
+                        .map(|line| {
+                                let mut row: String = line.into_iter().map(|c| c.to_string()).collect();
+                                row.push('\n');
+                                row
+                        })
+                        .collect()
+        }
 }
 
 /// Only valid chars in the CrossWordInput.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, FromStr)]
+/// (`Blank` is the `.` used to mask out letters in the puzzle's illustrations.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromStr, Display)]
 pub enum CWordChar {
         X,
         M,
         A,
         S,
+        #[display(".")]
+        Blank,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Constructor, IntoIterator, Index)]
 pub struct CWordLine {
@@ -186,6 +223,7 @@ impl CWordLine {
                                 'M' => CWordChar::M,
                                 'A' => CWordChar::A,
                                 'S' => CWordChar::S,
+                                '.' => CWordChar::Blank,
                                 no_parse => {
                                         return Err(ErrKindDay04::CWCharParse { uninterpretable_char: no_parse })?;
                                 }
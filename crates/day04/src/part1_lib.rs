@@ -11,8 +11,14 @@ use crate::{Result,
 pub fn process_part1(input: &str) -> Result<u64> {
         tea::trace!(%input);
         let puzzle = parse_input_1(input)?;
+        Ok(solve_part1(&puzzle))
+}
+
+/// Count of `XMAS`, in any direction, across all views of the puzzle.
+#[instrument(skip_all, ret(level = Level::DEBUG))]
+pub fn solve_part1(puzzle: &CWordPuzzle) -> u64 {
         let (h, v, dbl, dbr) = puzzle.count_rotations();
-        Ok(h + v + dbl + dbr)
+        h + v + dbl + dbr
 }
 
 impl CWordPuzzle {
@@ -151,13 +157,14 @@ impl SearchStateMachine {
 }
 #[cfg(test)]
 mod tests {
+        use aoc_core::{InputKind, Part, Solution as _};
         use indoc::indoc;
         use test_log::test;
         #[expect(unused)]
         use tracing::{self as tea, instrument};
 
         use super::*;
-        use crate::{EXAMPLE_INPUT_1, FINAL_INPUT};
+        use crate::{Day04, EXAMPLE_INPUT_1, ErrKindDay04, FINAL_INPUT};
 
         #[test]
         #[instrument]
@@ -204,17 +211,38 @@ mod tests {
                 let (h, v, dbl, dbr) = parse_input_1(input)?.count_rotations();
 
                 assert_eq!((hor_expected, vert_expected, diag_bltr_expected, diag_brtl_expected), (h, v, dbl, dbr));
-                let expected = 18;
-                assert_eq!(process_part1(input)?, expected);
+                let expected = Day04::recorded_answer(Part::Part1, InputKind::Example, input);
+                assert_eq!(process_part1(input)?.to_string(), expected);
                 Ok(())
         }
 
+        #[test]
+        fn ragged_rows_are_an_error() {
+                let truncated = EXAMPLE_INPUT_1.replacen("MSAMXMSMSA\n", "MSAMX\n", 1);
+                let err = process_part1(&truncated).expect_err("row 2 is cut short");
+                assert!(matches!(err, ErrKindDay04::RaggedRow { row: 2, len: 5, expected: 10 }), "{err}");
+        }
+
         #[test]
         #[instrument]
+        fn part1_example_input_reshaped_test() -> Result<()> {
+                use aoc_core::DynSolution as _;
+
+                let expected = Day04::recorded_answer(Part::Part1, InputKind::Example, EXAMPLE_INPUT_1);
+                let unterminated = EXAMPLE_INPUT_1.trim_end();
+                assert_eq!(process_part1(unterminated)?.to_string(), expected);
+                let crlf = EXAMPLE_INPUT_1.replace('\n', "\r\n");
+                assert_eq!(Day04.solve(Part::Part1, &crlf)?.answer, expected);
+                Ok(())
+        }
+
+        #[test]
+        #[ignore = "needs the private full input; run with `just test-full`"]
+        #[instrument]
         fn part1_final_input_test() -> Result<()> {
-                let input = FINAL_INPUT;
-                let expected = 2_560;
-                assert_eq!(process_part1(input)?, expected);
+                let input = &FINAL_INPUT.load()?;
+                let expected = Day04::recorded_answer(Part::Part1, InputKind::Full, input);
+                assert_eq!(process_part1(input)?.to_string(), expected);
                 Ok(())
         }
 }
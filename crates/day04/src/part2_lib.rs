@@ -15,7 +15,7 @@ use std::{sync::Arc, thread};
 use regex::Regex;
 use tracing::{self as tea, Level, instrument};
 
-use crate::{ErrKindDay04, Result};
+use crate::{ErrKindDay04, Result, parse::CWordPuzzle};
 
 #[instrument(skip_all, ret(level = Level::DEBUG))]
 pub fn process_part2(input: &str) -> Result<u64> {
@@ -27,6 +27,12 @@ pub fn process_part2(input: &str) -> Result<u64> {
         cross_mas_regex_count(input, row_length)
 }
 
+/// Count of 'cross-mas' patterns, on the puzzle's text form.
+#[instrument(skip_all, ret(level = Level::DEBUG))]
+pub fn solve_part2(puzzle: &CWordPuzzle) -> Result<u64> {
+        process_part2(&puzzle.to_text())
+}
+
 /// Count 'cross-mas' patterns in rectangular (by char) input text.
 /// Treats patterns as 1D and works on assumption of a consistent row-length across rows.
 ///
@@ -108,12 +114,13 @@ fn compile_mas_regexes(row_length: usize) -> (Regex, Regex, Regex, Regex) {
 }
 #[cfg(test)]
 mod tests {
+        use aoc_core::{InputKind, Part, Solution as _};
         use indoc::indoc;
         use test_log::test;
         use tracing::{self as tea, instrument};
 
         use super::*;
-        use crate::{EXAMPLE_INPUT_2, FINAL_INPUT};
+        use crate::{Day04, EXAMPLE_INPUT_2, FINAL_INPUT};
 
         #[test]
         #[instrument]
@@ -134,19 +141,29 @@ mod tests {
         #[instrument]
         fn part2_example_input_mas_test() -> Result<()> {
                 let input = EXAMPLE_INPUT_2;
-                let expected = 9;
-                assert_eq!(process_part2(input)?, expected);
+                let expected = Day04::recorded_answer(Part::Part2, InputKind::Example, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
+                Ok(())
+        }
+
+        #[test]
+        #[instrument]
+        fn part2_example_input_from_parsed() -> Result<()> {
+                let puzzle = crate::parse_input(EXAMPLE_INPUT_2)?;
+                assert_eq!(puzzle.to_text(), EXAMPLE_INPUT_2);
+                let expected = Day04::recorded_answer(Part::Part2, InputKind::Example, EXAMPLE_INPUT_2);
+                assert_eq!(solve_part2(&puzzle)?.to_string(), expected);
                 Ok(())
         }
 
-        // #[ignore = "Long runtime without release optimization"]
         #[test]
+        #[ignore = "needs the private full input; run with `just test-full`"]
         #[instrument]
         fn part2_final_input_mas_test() -> Result<()> {
                 tea::warn!("Long runtime without release optimization");
-                let input = FINAL_INPUT;
-                let expected = 1_910;
-                assert_eq!(process_part2(input)?, expected);
+                let input = &FINAL_INPUT.load()?;
+                let expected = Day04::recorded_answer(Part::Part2, InputKind::Full, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
                 Ok(())
         }
 }
@@ -14,10 +14,15 @@ pub enum ErrKindDay04 {
         #[from(ignore)]
         #[display("error parsing char: {}", uninterpretable_char)]
         CWCharParse { uninterpretable_char: char },
+        #[display("input error: {}", source)]
+        Input { source: aoc_core::Error },
         #[display("io error: {}", source)]
         Io { source: io::Error },
         #[display("Error extracting lines from input: {}", source_input)]
         NoInputLines { source_input: String },
+        #[from(ignore)]
+        #[display("Row {} is {} characters long, unlike the first row's {}", row, len, expected)]
+        RaggedRow { row: usize, len: usize, expected: usize },
         #[display("Error setting tracing subscriber default: {}", source)]
         TracingSubscriber { source: SetGlobalDefaultError },
         #[from(ignore)]
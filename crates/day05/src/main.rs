@@ -1,32 +1,44 @@
 //! CLI interface to run Parts 1 & 2 of Day05 of Advent of Code 2024.
 
-use std::path::PathBuf;
+use std::process::ExitCode;
 
-use clap::{Parser, Subcommand, ValueEnum};
-use day05::{CUSTOM_INPUT, EXAMPLE_INPUT, FINAL_INPUT, Result, active_global_default_tracing_subscriber, process_part1,
-            process_part2};
-use tracing::{self as tea, Level, instrument};
+use aoc_core::{CliDocs, Config, DynSolution as _, InputKind, InputPath, OutputFormat, Parts, RunReport, Variants,
+               default_input_dir};
+use clap::{Parser, ValueEnum};
+use day05::{Day05, Result, active_global_default_tracing_subscriber};
+use tracing::{self as tea, level_filters::LevelFilter};
 
 /// Choose to run Part 1 or 2 of Day05 of Advent of Code 2024.
 #[derive(Parser, Debug)]
-#[command(version, about, long_about, disable_help_subcommand = true, subcommand_help_heading = "input source")]
+#[command(version, about, long_about, subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
 pub struct Args {
-        /// Which Part to Run
-        part:  Part,
-        /// Input to use.
         #[command(subcommand)]
-        input: Option<Input>,
-}
-#[derive(Debug, Clone, ValueEnum)]
-pub enum Part {
-        /// Part 1, of day Day05
-        #[value(alias = "1", alias = "i", alias = "I", alias = "one")]
-        Part1,
-        /// Part 2, of day Day05
-        #[value(alias = "2", alias = "ii", alias = "II", alias = "two")]
-        Part2,
+        docs:       Option<CliDocs>,
+        /// Which Part to Run (`both` runs each on a single parse)
+        #[arg(required = true)]
+        part:       Option<Parts>,
+        /// Input to use.
+        input:      Option<Input>,
+        /// Read input from a file instead. (`-` for stdin)
+        #[arg(long = "input", value_name = "PATH", conflicts_with = "input")]
+        input_path: Option<InputPath>,
+        /// How to print the result.
+        #[arg(long, value_enum, default_value_t)]
+        format:     OutputFormat,
+        /// Answer the run should produce, repeated per part for `both`; exits with a distinct code on a wrong answer,
+        /// parse or solve error.
+        #[arg(long, value_name = "ANSWER")]
+        expect:     Vec<String>,
+        #[command(flatten)]
+        variants:   Variants,
+        /// Set level for active logging.
+        #[arg(long, short, value_enum)]
+        log:        Option<LevelFilter>,
+        /// Set level of logs that errors will collect.
+        #[arg(long, short, value_enum)]
+        error_log:  Option<LevelFilter>,
 }
-#[derive(Debug, Clone, Subcommand)]
+#[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum Input {
         /// Use the example input.
         Example,
@@ -34,56 +46,53 @@ pub enum Input {
         Full,
         /// Use a custom input.
         Custom,
-        /// Use custom input; please give a path.
-        Other { path: PathBuf },
+}
+impl From<Input> for InputKind {
+        fn from(input: Input) -> Self {
+                match input {
+                        Input::Example => InputKind::Example,
+                        Input::Full => InputKind::Full,
+                        Input::Custom => InputKind::Custom,
+                }
+        }
 }
 
-fn main() -> Result<()> {
-        let _tracing_writer_guard = active_global_default_tracing_subscriber()?;
+fn main() -> Result<ExitCode> {
+        let cli_user_args = Args::parse();
+        if let Some(docs) = cli_user_args.docs {
+                docs.print::<Args>()?;
+                return Ok(ExitCode::SUCCESS);
+        }
+        let parts = cli_user_args.part.expect("clap requires `part` unless printing docs");
+        let config = Config::load()?.day(Day05.day());
+        let _tracing_writer_guard = active_global_default_tracing_subscriber(
+                cli_user_args.log.or(config.log.level),
+                cli_user_args.error_log.or(config.log.error_level),
+        )?;
 
         let _enter = tea::debug_span!("main()").entered();
         tea::trace!("tracing subscriber set");
-        let cli_user_args = Args::parse();
         tea::trace!(?cli_user_args);
-        let part = cli_user_args.part;
-        let inp = cli_user_args.input.unwrap_or_else(|| {
-                tea::warn!("-- No input given.  Using Example input. -- ");
-                Input::Example
-        });
-        tea::trace!(?part, ?inp);
-
-        match (part, inp) {
-                (Part::Part1, inp) => main_part1(inp),
-                (Part::Part2, inp) => main_part2(inp),
-        }?;
+        let input_dir = default_input_dir();
+        let kind = cli_user_args
+                .input
+                .map(InputKind::from)
+                .or(config.input)
+                .unwrap_or_else(|| {
+                        if cli_user_args.input_path.is_none() {
+                                tea::warn!("-- No input given.  Using Example input. -- ");
+                        }
+                        InputKind::Example
+                });
+        let reports = RunReport::run_parts(
+                &Day05,
+                parts,
+                cli_user_args.input_path.as_ref(),
+                kind,
+                &input_dir,
+                &cli_user_args.expect,
+                &cli_user_args.variants,
+        )?;
         tea::trace!("finishing main()");
-        Ok(())
-}
-
-/// Run Part1_Lib code on binary-bound input1.txt
-#[instrument(ret(level = Level::DEBUG))]
-pub fn main_part1(input: Input) -> Result<u64> {
-        let input = match input {
-                Input::Example => EXAMPLE_INPUT,
-                Input::Full => FINAL_INPUT,
-                Input::Custom => CUSTOM_INPUT,
-                Input::Other { path } => &std::fs::read_to_string(path)?,
-        };
-        let val = process_part1(input)?;
-        tea::info!(?val, "Part 1 Process result.");
-        Ok(val)
-}
-
-/// Run Part2_Lib code on binary-bound input2.txt
-#[instrument(ret(level = Level::DEBUG))]
-pub fn main_part2(input: Input) -> Result<u64> {
-        let input = match input {
-                Input::Example => EXAMPLE_INPUT,
-                Input::Full => FINAL_INPUT,
-                Input::Custom => CUSTOM_INPUT,
-                Input::Other { path } => &std::fs::read_to_string(path)?,
-        };
-        let val = process_part2(input)?;
-        tea::info!(?val, "Part 2 Process result.");
-        Ok(val)
+        Ok(RunReport::print_all(&reports, cli_user_args.format)?)
 }
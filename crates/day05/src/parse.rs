@@ -8,46 +8,18 @@
 //!   - Sequences
 //!     - n,n,n,n,n,n...   <--should be an odd number of values
 
-use std::{collections::{HashMap, HashSet},
-          sync::OnceLock};
+use std::{cmp::Ordering,
+          collections::{HashMap, HashSet}};
 
 use derive_more::derive::{Constructor, Deref, DerefMut, Display, From, FromStr, Into, IntoIterator};
 use tracing::{self as tea, Level, instrument};
 
 use crate::{Result, support::ErrKindDay05};
 
-pub static PAGE_RELATIONS: OnceLock<PageRelations> = OnceLock::new();
-
 /// A single page number.
 #[derive(Debug, Clone, Constructor, PartialEq, Eq, From, Into, Deref, DerefMut, Copy, FromStr, Hash, Display)]
 #[display("p_{}", _0)]
 pub struct Page(u32);
-impl PartialOrd for Page {
-        /// This assumes that all elements encountered were represented in the rules.
-        #[instrument]
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                Some(self.cmp(other))
-        }
-}
-impl Ord for Page {
-        /// This assumes that all elements encountered were represented in the rules.
-        #[instrument]
-        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-                tea::debug!(%self, %other);
-                tea::debug!(?PAGE_RELATIONS);
-                tea::debug!(get_got = ?PAGE_RELATIONS.get());
-
-                match PAGE_RELATIONS
-                        .get()
-                        .expect("static should have been set")
-                        .say_pair_are_ordered((*self, *other))
-                {
-                        Some(true) => std::cmp::Ordering::Less,
-                        Some(false) => std::cmp::Ordering::Greater,
-                        None => std::cmp::Ordering::Equal,
-                }
-        }
-}
 /// A relationship between two page numbers.
 #[derive(Debug, Clone, Constructor, From, PartialEq, Eq, Into, Display)]
 #[display("{} < {}", less, more)]
@@ -74,6 +46,17 @@ impl PageRelations {
                 self.get(&less).map(|rp| rp.greater_pages.contains(&more))
         }
 
+        /// Ordering of two pages according to the rules; for use with `sort_by`.
+        /// This assumes that all elements encountered were represented in the rules.
+        #[instrument(level = Level::DEBUG, skip(self))]
+        pub fn compare(&self, left: Page, right: Page) -> Ordering {
+                match self.say_pair_are_ordered((left, right)) {
+                        Some(true) => Ordering::Less,
+                        Some(false) => Ordering::Greater,
+                        None => Ordering::Equal,
+                }
+        }
+
         #[instrument()]
         pub fn verify_total_ordering_shape(&self) -> Result<()> {
                 tea::trace!(?self);
@@ -117,7 +100,7 @@ pub fn parse_input(raw_input: &str) -> Result<(PageRelations, Vec<PageSequence>)
         let mut to_check: Vec<PageSequence> = Vec::new();
         let mut page_rels = PageRelations::new(HashMap::new());
 
-        let (order_relations, sequences) = raw_input.split_once("\n\n").expect("blank line to split input on");
+        let (order_relations, sequences) = raw_input.split_once("\n\n").ok_or(ErrKindDay05::MissingSectionBreak)?;
         for line in order_relations.lines() {
                 let (less, more) = line
                         .split_once('|')
@@ -176,4 +159,10 @@ mod tests {
                 assert_eq!(vec!(1, 2, 3, 4), relations);
                 Ok(())
         }
+
+        #[test]
+        fn missing_section_break_is_an_error() {
+                let err = parse_input("1|2\n1,2\n").expect_err("no blank line");
+                assert!(matches!(err.source, ErrKindDay05::MissingSectionBreak), "{err}");
+        }
 }
@@ -3,13 +3,20 @@
 
 use tracing::{self as tea, Level, instrument};
 
-use crate::{Result, parse::parse_input};
+use crate::{Result,
+            parse::{PageRelations, PageSequence, parse_input}};
 
 #[instrument(skip_all, ret(level = Level::DEBUG))]
 pub fn process_part1(input: &str) -> Result<u64> {
         tea::trace!(%input);
-        let mut total = 0;
         let (page_relations, to_check) = parse_input(input)?;
+        solve_part1(&page_relations, &to_check)
+}
+
+/// Sum of middle pages, over sequences already in order.
+#[instrument(skip_all, ret(level = Level::DEBUG))]
+pub fn solve_part1(page_relations: &PageRelations, to_check: &[PageSequence]) -> Result<u64> {
+        let mut total = 0;
         if cfg!(debug_assertions) {
                 // throwing error: spantrace collection
                 page_relations.verify_total_ordering_shape()?
@@ -33,13 +40,13 @@ pub fn process_part1(input: &str) -> Result<u64> {
 
 #[cfg(test)]
 mod tests {
-
+        use aoc_core::{InputKind, Part, Solution as _};
         use indoc::indoc;
         use test_log::test;
         use tracing::instrument;
 
         use super::*;
-        use crate::{EXAMPLE_INPUT, FINAL_INPUT,
+        use crate::{Day05, EXAMPLE_INPUT, FINAL_INPUT,
                     support::{ErrKindDay05, ErrWrapperDay05}};
 
         #[test()]
@@ -63,19 +70,19 @@ mod tests {
         #[instrument]
         fn test_process_example() -> Result<()> {
                 let input = EXAMPLE_INPUT;
-                let expected = 143;
-                assert_eq!(process_part1(input)?, expected);
+                let expected = Day05::recorded_answer(Part::Part1, InputKind::Example, input);
+                assert_eq!(process_part1(input)?.to_string(), expected);
                 Ok(())
         }
 
         /// Test's expected value to be populated after solution verification.
-        /// NOTE: `#[ignore]` is set for this test by default.
         #[test]
+        #[ignore = "needs the private full input; run with `just test-full`"]
         #[instrument]
         fn test_process_problem_input() -> Result<()> {
-                let input = FINAL_INPUT;
-                let expected = 5268;
-                assert_eq!(process_part1(input)?, expected);
+                let input = &FINAL_INPUT.load()?;
+                let expected = Day05::recorded_answer(Part::Part1, InputKind::Full, input);
+                assert_eq!(process_part1(input)?.to_string(), expected);
                 Ok(())
         }
 }
@@ -4,36 +4,37 @@
 use tracing::{self as tea, Level, instrument};
 
 use crate::{Result,
-            parse::{PAGE_RELATIONS, parse_input},
-            support::ErrKindDay05};
+            parse::{PageRelations, PageSequence, parse_input}};
 
 #[instrument(skip_all, ret(level = Level::DEBUG))]
 pub fn process_part2(input: &str) -> Result<u64> {
         tea::trace!(%input);
-        let mut total = 0;
-        let (page_relations, mut to_check) = parse_input(input)?;
-        PAGE_RELATIONS
-                .set(page_relations)
-                .map_err(|_| ErrKindDay05::StaticPageRelationsSetFailure)?;
+        let (page_relations, to_check) = parse_input(input)?;
+        solve_part2(&page_relations, &to_check)
+}
 
+/// Sum of middle pages, over out of order sequences once sorted.
+#[instrument(skip_all, ret(level = Level::DEBUG))]
+pub fn solve_part2(page_relations: &PageRelations, to_check: &[PageSequence]) -> Result<u64> {
+        let mut total = 0;
         if cfg!(debug_assertions) {
                 // throwing error: spantrace collection
-                PAGE_RELATIONS.get().unwrap().verify_total_ordering_shape()?
+                page_relations.verify_total_ordering_shape()?
         }
         let _tea = tea::info_span!(target: "q_pop", "abberant popping").entered();
         tea::trace!("hello?");
-        for i in 0..to_check.len() {
-                tea::trace!(target: "q_pop",i, ?to_check);
-                let mut seq = to_check.pop().unwrap();
+        for (i, seq) in to_check.iter().enumerate() {
+                tea::trace!(target: "q_pop", i);
+                let mut seq = seq.clone();
                 tea::trace!(target: "q_pop",?seq);
                 if !seq.windows(2).all(|page_slice| {
                         match page_slice {
-                                &[l, r] => PAGE_RELATIONS.get().unwrap().say_pair_are_ordered((l, r)),
+                                &[l, r] => page_relations.say_pair_are_ordered((l, r)),
                                 _ => unreachable!(),
                         }
                         .unwrap_or(true)
                 }) {
-                        seq.sort_unstable();
+                        seq.sort_unstable_by(|&l, &r| page_relations.compare(l, r));
                         // add value of middle element
                         total += *seq[seq.len() / 2] as u64;
                         debug_assert!(seq.len() % 2 == 1)
@@ -44,34 +45,30 @@ pub fn process_part2(input: &str) -> Result<u64> {
 
 #[cfg(test)]
 mod tests {
+        use aoc_core::{InputKind, Part, Solution as _};
         use test_log::test;
         use tracing::instrument;
 
         use super::*;
-        use crate::{EXAMPLE_INPUT, FINAL_INPUT};
+        use crate::{Day05, EXAMPLE_INPUT, FINAL_INPUT};
 
         #[test]
         #[instrument]
         fn test_process_example() -> Result<()> {
                 let input = EXAMPLE_INPUT;
-                let expected = 123;
-                tea::warn!(
-                        "OnceLock(|Cell) means that the single-process tests of default `Cargo test` will interfere with one another.  Use `Cargo Nextest`. (Unclear if mutex to force test serialization will be sufficient, as the OnceLock is static."
-                );
-                assert_eq!(process_part2(input)?, expected);
+                let expected = Day05::recorded_answer(Part::Part2, InputKind::Example, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
                 Ok(())
         }
 
         /// Test's expected value to be populated after solution verification.
         #[test]
+        #[ignore = "needs the private full input; run with `just test-full`"]
         #[instrument]
         fn test_process_problem_input() -> Result<()> {
-                let input = FINAL_INPUT;
-                let expected = 5799;
-                tea::warn!(
-                        "OnceLock(|Cell) means that the single-process tests of default `Cargo test` will interfere with one another.  Use `Cargo Nextest`. (Unclear if mutex to force test serialization will be sufficient, as the OnceLock is static."
-                );
-                assert_eq!(process_part2(input)?, expected);
+                let input = &FINAL_INPUT.load()?;
+                let expected = Day05::recorded_answer(Part::Part2, InputKind::Full, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
                 Ok(())
         }
 }
@@ -14,6 +14,8 @@ use tracing::subscriber::SetGlobalDefaultError; // !
 // use derive_more::{Display, Error, derive::From};
 #[derive(Debug, Display, From, Error)]
 pub enum ErrKindDay05 {
+        #[display("input error: {}", source)]
+        Input { source: aoc_core::Error },
         #[display("io error: {}", source)]
         Io { source: io::Error },
         #[display("parse error: {}", source)]
@@ -25,10 +27,10 @@ pub enum ErrKindDay05 {
         OtherDynError { source: Box<dyn std::error::Error + Send + Sync> },
         #[display("Error extracting lines from input: {}", source_input)]
         OrderPatternError { source_input: String },
+        #[display("No blank line between the ordering rules and the page sequences")]
+        MissingSectionBreak,
         #[display("Ordering Shapes does not Allow for rules to be a total ordering")]
         NonTotalOrderingShape,
-        #[display("Unable to set the Static Page Relations OnceLock")]
-        StaticPageRelationsSetFailure,
         // #[from(ignore)]
         // #[display("error parsing char: {}", uninterpretable_char)]
         // CharParse { uninterpretable_char: char },
@@ -21,6 +21,9 @@ use tracing_subscriber::{fmt::format::FmtSpan, prelude::*};
 
 use crate::Result;
 
+const DEFAULT_LOGGING_LEVEL: LevelFilter = LevelFilter::TRACE;
+const DEFAULT_ERROR_LOGGING_LEVEL: LevelFilter = LevelFilter::TRACE;
+
 /// (Convenience function.) Generates a tracing_subcsriber and sets it as global default, while returning a writer guard.
 ///
 /// # Caveat
@@ -34,9 +37,12 @@ use crate::Result;
 ///    Ok(())
 /// }
 /// ```
-pub fn active_global_default_tracing_subscriber() -> Result<WorkerGuard> {
+pub fn active_global_default_tracing_subscriber(
+        env_default_level: Option<LevelFilter>,
+        trace_error_level: Option<LevelFilter>,
+) -> Result<WorkerGuard> {
         let envfilter_layer = tracing_subscriber::EnvFilter::builder()
-                .with_default_directive(LevelFilter::TRACE.into())
+                .with_default_directive(env_default_level.unwrap_or(DEFAULT_LOGGING_LEVEL).into())
                 .from_env_lossy();
 
         // let tree_layer = tracing_tree::HierarchicalLayer::new(2)
@@ -45,7 +51,7 @@ pub fn active_global_default_tracing_subscriber() -> Result<WorkerGuard> {
         //         .with_indent_lines(true)
         //         .with_targets(true);
 
-        let error_layer = ErrorLayer::default().with_filter(LevelFilter::TRACE);
+        let error_layer = ErrorLayer::default().with_filter(trace_error_level.unwrap_or(DEFAULT_ERROR_LOGGING_LEVEL));
 
         let (non_blocking_writer, trace_writer_guard) = tracing_appender::non_blocking(std::io::stderr());
         let fmt_layer = tracing_subscriber::fmt::Layer::default()
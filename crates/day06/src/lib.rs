@@ -5,11 +5,72 @@ mod part1_lib;
 mod part2_lib;
 pub mod support;
 
-pub use parse::parse_input;
-pub use part1_lib::{PopulatedMaze, process_part1};
-pub use part2_lib::{PopulatedMazeWHSet, UpdateError, process_part2};
+use aoc_core::{InputKind, InputSource, Part, Solution, Variant};
+pub use parse::{Guard, Maze, parse_input};
+pub use part1_lib::{PopulatedMaze, parse_input_with_guard, process_part1, solve_part1, solve_part1_hashset_loop_check,
+                    solve_part1_vec_loop_check};
+pub use part2_lib::{PopulatedMazeWHSet, UpdateError, process_part2, solve_both, solve_part2};
 pub use support::{Error, Result, activate_global_default_tracing_subscriber};
 
-pub const FINAL_INPUT: &str = include_str!("../data/final_input.txt");
 pub const EXAMPLE_INPUT: &str = include_str!("../data/example_input.txt");
 pub const CUSTOM_INPUT: &str = include_str!("../data/custom_input.txt");
+/// Full puzzle input: read at runtime from the input directory, or embedded with the `embed-inputs` feature.
+#[cfg(not(feature = "embed-inputs"))]
+pub const FINAL_INPUT: InputSource = InputSource::File { day: 6, name: "final_input.txt" };
+#[cfg(feature = "embed-inputs")]
+pub const FINAL_INPUT: InputSource = InputSource::Embedded(include_str!("../../../data/no-sync/day06/final_input.txt"));
+pub const ANSWERS: &str = include_str!("../data/answers.toml");
+
+/// Day06, as registered with the workspace `aoc` runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Day06;
+impl Solution for Day06 {
+        type Answer = usize;
+        type Error = Error;
+        type Parsed = (Maze, Guard);
+
+        const ANSWERS: &'static str = ANSWERS;
+        const DAY: u8 = 6;
+        const TITLE: &'static str = "Guard Gallivant";
+
+        fn input(kind: InputKind, _part: Part) -> Option<InputSource> {
+                match kind {
+                        InputKind::Example => Some(InputSource::Embedded(EXAMPLE_INPUT)),
+                        InputKind::Full => Some(FINAL_INPUT),
+                        InputKind::Custom => Some(InputSource::Embedded(CUSTOM_INPUT)),
+                }
+        }
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+                parse_input_with_guard(input)
+        }
+
+        fn part1((maze, guard): &Self::Parsed) -> Result<Self::Answer> {
+                solve_part1(maze, *guard)
+        }
+
+        fn part2((maze, guard): &Self::Parsed) -> Result<Self::Answer> {
+                solve_part2(maze, *guard)
+        }
+
+        fn both((maze, guard): &Self::Parsed) -> Result<(Self::Answer, Self::Answer)> {
+                solve_both(maze, *guard)
+        }
+
+        fn variants(part: Part) -> &'static [Variant<Self>] {
+                match part {
+                        Part::Part1 => &[
+                                Variant { name: "initial-state", solve: |(maze, guard)| solve_part1(maze, *guard) },
+                                Variant {
+                                        name:  "vec-loop-check",
+                                        solve: |(maze, guard)| solve_part1_vec_loop_check(maze, *guard),
+                                },
+                                Variant {
+                                        name:  "hashset-loop-check",
+                                        solve: |(maze, guard)| solve_part1_hashset_loop_check(maze, *guard),
+                                },
+                        ],
+                        Part::Part2 => &[],
+                }
+        }
+}
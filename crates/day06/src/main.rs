@@ -1,33 +1,42 @@
 //! CLI interface to run Parts 1 & 2 of Day06 of Advent of Code 2024.
 
+use std::process::ExitCode;
+
+use aoc_core::{CliDocs, Config, DynSolution as _, InputKind, InputPath, OutputFormat, Parts, RunReport, Variants,
+               default_input_dir};
 use clap::{Parser, ValueEnum};
-use day06::{CUSTOM_INPUT, EXAMPLE_INPUT, FINAL_INPUT, Result, activate_global_default_tracing_subscriber,
-            process_part1, process_part2};
-use tracing::{self as tea, Level, instrument, level_filters::LevelFilter};
+use day06::{Day06, Result, activate_global_default_tracing_subscriber};
+use tracing::{self as tea, level_filters::LevelFilter};
 
 /// Choose to run Part 1 or 2 of Day06 of Advent of Code 2024.
 #[derive(Parser, Debug)]
-#[command(version, about, long_about)]
+#[command(version, about, long_about, subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
 pub struct Args {
-        /// Which Part to Run
-        part:      Part,
+        #[command(subcommand)]
+        docs:       Option<CliDocs>,
+        /// Which Part to Run (`both` runs each on a single parse)
+        #[arg(required = true)]
+        part:       Option<Parts>,
         /// Input to use.
-        input:     Option<Input>,
+        input:      Option<Input>,
+        /// Read input from a file instead. (`-` for stdin)
+        #[arg(long = "input", value_name = "PATH", conflicts_with = "input")]
+        input_path: Option<InputPath>,
+        /// How to print the result.
+        #[arg(long, value_enum, default_value_t)]
+        format:     OutputFormat,
+        /// Answer the run should produce, repeated per part for `both`; exits with a distinct code on a wrong answer,
+        /// parse or solve error.
+        #[arg(long, value_name = "ANSWER")]
+        expect:     Vec<String>,
+        #[command(flatten)]
+        variants:   Variants,
         /// Set level for active logging.
         #[arg(long, short, value_enum)]
-        log:       Option<LevelFilter>,
+        log:        Option<LevelFilter>,
         /// Set level of logs that errors will collect.
         #[arg(long, short, value_enum)]
-        error_log: Option<LevelFilter>,
-}
-#[derive(Debug, Clone, Copy, ValueEnum)]
-pub enum Part {
-        /// Part 1, of day Day06
-        #[value(alias = "1", alias = "i", alias = "I", alias = "one")]
-        Part1,
-        /// Part 2, of day Day06
-        #[value(alias = "2", alias = "ii", alias = "II", alias = "two")]
-        Part2,
+        error_log:  Option<LevelFilter>,
 }
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum Input {
@@ -38,62 +47,60 @@ pub enum Input {
         /// Use a custom input.
         Custom,
 }
+impl From<Input> for InputKind {
+        fn from(input: Input) -> Self {
+                match input {
+                        Input::Example => InputKind::Example,
+                        Input::Full => InputKind::Full,
+                        Input::Custom => InputKind::Custom,
+                }
+        }
+}
 
-fn main() -> Result<()> {
-        let cli_user_args = Args::try_parse()?;
+fn main() -> Result<ExitCode> {
+        let cli_user_args = Args::parse();
+        if let Some(docs) = cli_user_args.docs {
+                docs.print::<Args>()?;
+                return Ok(ExitCode::SUCCESS);
+        }
+        let parts = cli_user_args.part.expect("clap requires `part` unless printing docs");
+        let config = Config::load()?.day(Day06.day());
 
         // #[cfg(debug_assertions)]
         // skip setting up subscriber if both passed log values are `OFF`
-        let _mb_writer_guard: Option<tracing_appender::non_blocking::WorkerGuard> =
-                match (cli_user_args.log, cli_user_args.error_log) {
-                        (Some(LevelFilter::OFF), Some(LevelFilter::OFF)) => None,
-                        (_, _) => Some(activate_global_default_tracing_subscriber()
-                                .maybe_env_default_level(cli_user_args.log)
-                                .maybe_trace_error_level(cli_user_args.error_log)
-                                .call()?),
-                };
+        let (log, error_log) =
+                (cli_user_args.log.or(config.log.level), cli_user_args.error_log.or(config.log.error_level));
+        let _mb_writer_guard: Option<tracing_appender::non_blocking::WorkerGuard> = match (log, error_log) {
+                (Some(LevelFilter::OFF), Some(LevelFilter::OFF)) => None,
+                (_, _) => Some(activate_global_default_tracing_subscriber()
+                        .maybe_env_default_level(log)
+                        .maybe_trace_error_level(error_log)
+                        .call()?),
+        };
 
         let _enter = tea::debug_span!("main()").entered();
         tea::trace!("tracing subscriber set");
         tea::trace!(?cli_user_args);
-        let part = cli_user_args.part;
-        let inp = cli_user_args.input.unwrap_or_else(|| {
-                tea::warn!("-- No input given.  Using Example input. -- ");
-                Input::Example
-        });
-        tea::trace!(?part, ?inp);
-
-        let solution = match (part, inp) {
-                (Part::Part1, inp) => main_part1(inp),
-                (Part::Part2, inp) => main_part2(inp),
-        }?;
-        println!("Calculated solution: {}", solution);
+        let input_dir = default_input_dir();
+        let kind = cli_user_args
+                .input
+                .map(InputKind::from)
+                .or(config.input)
+                .unwrap_or_else(|| {
+                        if cli_user_args.input_path.is_none() {
+                                tea::warn!("-- No input given.  Using Example input. -- ");
+                        }
+                        InputKind::Example
+                });
+        let reports = RunReport::run_parts(
+                &Day06,
+                parts,
+                cli_user_args.input_path.as_ref(),
+                kind,
+                &input_dir,
+                &cli_user_args.expect,
+                &cli_user_args.variants,
+        )?;
         tea::trace!("finishing main()");
-        Ok(())
-}
-
-/// Run Part1_Lib code on binary-bound input1.txt
-#[instrument(skip_all, ret(level = Level::DEBUG))]
-pub fn main_part1(input: Input) -> Result<usize> {
-        let input = match input {
-                Input::Example => EXAMPLE_INPUT,
-                Input::Full => FINAL_INPUT,
-                Input::Custom => CUSTOM_INPUT,
-        };
-        let val = process_part1(input)?;
-        tea::info!(?val, "Part 1 Process result.");
-        Ok(val)
-}
-
-/// Run Part2_Lib code on binary-bound input2.txt
-#[instrument(skip_all, ret(level = Level::DEBUG))]
-pub fn main_part2(input: Input) -> Result<usize> {
-        let input = match input {
-                Input::Example => EXAMPLE_INPUT,
-                Input::Full => FINAL_INPUT,
-                Input::Custom => CUSTOM_INPUT,
-        };
-        let val = process_part2(input)?;
-        tea::info!(?val, "Part 2 Process result.");
-        Ok(val)
+        Ok(RunReport::print_all(&reports, cli_user_args.format)?)
 }
@@ -5,23 +5,48 @@ use itertools::Itertools as _;
 use owo_colors::OwoColorize as _;
 use tracing::{Level, instrument};
 
-use crate::{Result,
+use crate::{PopulatedMazeWHSet, Result, UpdateError,
             parse::{Direction, Guard, Maze, PositionState, parse_input},
             support::error::ErrKindDay06};
 
-#[cfg(not(feature = "loop-checking-hashset_p1"))]
 #[instrument(skip_all, ret(level = Level::INFO))]
 pub fn process_part1(input: &str) -> Result<usize> {
-        #[cfg(not(feature = "loop-checking-vec_p1"))]
-        {
-                tracing::event!(
-                        Level::WARN,
-                        "Loop checking disabled.  Loops total loops (those including initial position) are checked.  Use `--features ...` to enable general loop checking (may be multiple methods)."
-                );
-        }
+        let (maze, guard_initial) = parse_input_with_guard(input)?;
+        solve_part1(&maze, guard_initial)
+}
+
+/// Parse input, requiring a guard be present. (Both parts need one.)
+#[instrument(skip_all)]
+pub fn parse_input_with_guard(input: &str) -> Result<(Maze, Guard)> {
         let (maze, mb_guard) = parse_input(input)?;
-        let guard_initial = mb_guard.ok_or(ErrKindDay06::NoGuardFound { source_input: Some(input.to_string()) })?;
-        let mut pop_maze = PopulatedMaze::new(maze, guard_initial)?;
+        let guard = mb_guard.ok_or(ErrKindDay06::NoGuardFound { source_input: Some(input.to_string()) })?;
+        Ok((maze, guard))
+}
+
+/// Count of distinct positions the guard visits before leaving the maze.
+///
+/// Loops are only caught if they pass through the guard's initial state. (Quickest; see the other variants.)
+#[instrument(skip_all, ret(level = Level::INFO))]
+pub fn solve_part1(maze: &Maze, guard_initial: Guard) -> Result<usize> {
+        tracing::event!(
+                Level::WARN,
+                "Loop checking disabled.  Loops total loops (those including initial position) are checked.  Use `--variant ...` to enable general loop checking (may be multiple methods)."
+        );
+        walk_part1(maze, guard_initial, false)
+}
+
+/// As [`solve_part1`], checking every new guard state against all previous ones.
+///
+/// About 100% increase in runtime for full input, over the non-exhaustive loop checked version.
+/// (This is notable, as the max length of the vector is under 5_000 items.)
+#[instrument(skip_all, ret(level = Level::INFO))]
+pub fn solve_part1_vec_loop_check(maze: &Maze, guard_initial: Guard) -> Result<usize> {
+        walk_part1(maze, guard_initial, true)
+}
+
+/// Walk the guard out of the maze, stopping on a loop: one through the initial state, or any if `check_all_states`.
+fn walk_part1(maze: &Maze, guard_initial: Guard, check_all_states: bool) -> Result<usize> {
+        let mut pop_maze = PopulatedMaze::new(maze.clone(), guard_initial)?;
         for _ in 0.. {
                 let opt_guard_update = pop_maze.update();
                 #[cfg(feature = "manual-walkthrough_p1")]
@@ -35,16 +60,13 @@ pub fn process_part1(input: &str) -> Result<usize> {
                 }
 
                 if let Some(guard_update) = opt_guard_update {
-                        #[cfg(not(feature = "loop-checking-vec_p1"))]
-                        if guard_update != guard_initial {
-                                continue;
-                        }
-                        #[cfg(feature = "loop-checking-vec_p1")]
-                        {
+                        let repeated = match check_all_states {
                                 // skip last element, as what we're checking was already pushed on vec
-                                if !pop_maze.guard_time_path.iter().rev().skip(1).contains(&guard_update) {
-                                        continue;
-                                }
+                                true => pop_maze.guard_time_path.iter().rev().skip(1).contains(&guard_update),
+                                false => guard_update == guard_initial,
+                        };
+                        if !repeated {
+                                continue;
                         }
                 }
                 // repeat or None
@@ -55,16 +77,13 @@ pub fn process_part1(input: &str) -> Result<usize> {
         Ok(distinct_positions)
 }
 
+/// As [`solve_part1`], checking guard states against a hashset of previous ones.
+///
 /// Slightly slower (about 33% increase in runtime for full input) than non-exhaustive loop checked version.
 /// But much quicker than exhaustive loop checking over vector save of guard states (which is about 100% increase).
-/// (This is notable, as the max length of the vector is under 5_000 items.)
-#[cfg(feature = "loop-checking-hashset_p1")]
 #[instrument(skip_all, ret(level = Level::INFO))]
-pub fn process_part1(input: &str) -> Result<usize> {
-        use crate::{PopulatedMazeWHSet, UpdateError};
-        let (maze, mb_guard) = parse_input(input)?;
-        let guard_initial = mb_guard.ok_or(ErrKindDay06::NoGuardFound { source_input: Some(input.to_string()) })?;
-        let mut pop_maze = PopulatedMazeWHSet::new(maze, guard_initial)?;
+pub fn solve_part1_hashset_loop_check(maze: &Maze, guard_initial: Guard) -> Result<usize> {
+        let mut pop_maze = PopulatedMazeWHSet::new(maze.clone(), guard_initial)?;
         for _ in 0.. {
                 match pop_maze.update() {
                         Ok(_) => {
@@ -177,31 +196,43 @@ impl std::fmt::Display for PopulatedMaze {
 
 #[cfg(test)]
 mod tests {
+        use aoc_core::{InputKind, Part, Solution as _};
         use pretty_assertions::assert_eq;
         use test_log::test;
         use tracing::instrument;
 
         use super::*;
-        use crate::{EXAMPLE_INPUT, FINAL_INPUT};
+        use crate::{Day06, EXAMPLE_INPUT, FINAL_INPUT};
 
         #[test]
         #[cfg_attr(feature = "manual-walkthrough_p1", ignore = "Manual interaction required")]
         #[instrument]
         fn test_process_example() -> Result<()> {
                 let input = EXAMPLE_INPUT;
-                let expected = 41;
-                assert_eq!(process_part1(input)?, expected);
+                let expected = Day06::recorded_answer(Part::Part1, InputKind::Example, input);
+                assert_eq!(process_part1(input)?.to_string(), expected);
                 Ok(())
         }
 
-        /// Test's expected value to be populated after solution verification.
         #[test]
         #[cfg_attr(feature = "manual-walkthrough_p1", ignore = "Manual interaction required")]
+        fn test_variants_agree_on_example() -> Result<()> {
+                let (maze, guard) = parse_input_with_guard(EXAMPLE_INPUT)?;
+                let expected = Day06::recorded_answer(Part::Part1, InputKind::Example, EXAMPLE_INPUT);
+                for solve in [solve_part1, solve_part1_vec_loop_check, solve_part1_hashset_loop_check] {
+                        assert_eq!(solve(&maze, guard)?.to_string(), expected);
+                }
+                Ok(())
+        }
+
+        /// Test's expected value to be populated after solution verification.
+        #[test]
+        #[ignore = "needs the private full input; run with `just test-full`"]
         #[instrument]
         fn test_process_problem_input() -> Result<()> {
-                let input = FINAL_INPUT;
-                let expected = 4_711;
-                assert_eq!(process_part1(input)?, expected);
+                let input = &FINAL_INPUT.load()?;
+                let expected = Day06::recorded_answer(Part::Part1, InputKind::Full, input);
+                assert_eq!(process_part1(input)?.to_string(), expected);
                 Ok(())
         }
 }
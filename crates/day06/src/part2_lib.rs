@@ -9,44 +9,67 @@ use rayon::prelude::*;
 use tracing::{Level, instrument};
 
 use crate::{Result,
-            parse::{Direction, Guard, Maze, Point2D, PositionState, parse_input},
+            parse::{Direction, Guard, Maze, Point2D, PositionState},
+            part1_lib::parse_input_with_guard,
             support::error::ErrKindDay06};
 
 #[instrument(skip_all, ret(level = Level::INFO))]
 pub fn process_part2(input: &str) -> Result<usize> {
-        let (pop_maze_base, starting_position) = {
-                let (maze, mb_guard) = parse_input(input)?;
-                let guard_initial =
-                        mb_guard.ok_or(ErrKindDay06::NoGuardFound { source_input: Some(input.to_string()) })?;
-                (PopulatedMazeWHSet::new(maze, guard_initial)?, guard_initial.pos)
-        };
-        let mut original_path_positions: HashSet<Point2D> = {
-                let mut pop_maze = pop_maze_base.clone();
-                loop {
-                        match pop_maze.update() {
-                                Ok(_) => {
-                                        continue;
-                                }
-                                Err(UpdateError::LoopDetected) => {
-                                        tracing::event![Level::INFO, "Loop detected."];
-                                        break;
-                                }
-                                Err(UpdateError::GuardOutOfBounds) => {
-                                        tracing::event![Level::INFO, "Guard Exited Maze."];
-                                        break;
-                                }
-                                Err(UpdateError::NoMoveAvailable) => {
-                                        tracing::event![Level::WARN, "Guard trapped on a single tile."];
-                                        break;
-                                }
+        let (maze, guard_initial) = parse_input_with_guard(input)?;
+        solve_part2(&maze, guard_initial)
+}
+
+/// Count of positions where a single new obstacle would trap the guard in a loop.
+#[instrument(skip_all, ret(level = Level::INFO))]
+pub fn solve_part2(maze: &Maze, guard_initial: Guard) -> Result<usize> {
+        let original_path_positions = original_path_positions(maze, guard_initial)?;
+        count_loop_obstacles(maze, guard_initial, original_path_positions)
+}
+
+/// Both parts, walking the guard's original path only once.
+/// (Part 1 is the count of positions on that path; Part 2 tries an obstacle on each of them.)
+#[instrument(skip_all, ret(level = Level::INFO))]
+pub fn solve_both(maze: &Maze, guard_initial: Guard) -> Result<(usize, usize)> {
+        let original_path_positions = original_path_positions(maze, guard_initial)?;
+        let part1 = original_path_positions.len();
+        Ok((part1, count_loop_obstacles(maze, guard_initial, original_path_positions)?))
+}
+
+/// Distinct positions the guard visits before leaving the maze (or looping).
+#[instrument(skip_all)]
+pub fn original_path_positions(maze: &Maze, guard_initial: Guard) -> Result<HashSet<Point2D>> {
+        let mut pop_maze = PopulatedMazeWHSet::new(maze.clone(), guard_initial)?;
+        loop {
+                match pop_maze.update() {
+                        Ok(_) => {
+                                continue;
+                        }
+                        Err(UpdateError::LoopDetected) => {
+                                tracing::event![Level::INFO, "Loop detected."];
+                                break;
+                        }
+                        Err(UpdateError::GuardOutOfBounds) => {
+                                tracing::event![Level::INFO, "Guard Exited Maze."];
+                                break;
+                        }
+                        Err(UpdateError::NoMoveAvailable) => {
+                                tracing::event![Level::WARN, "Guard trapped on a single tile."];
+                                break;
                         }
                 }
-                pop_maze.guard_states
-                        .into_iter()
-                        .map(|guard| guard.pos)
-                        .unique()
-                        .collect()
-        };
+        }
+        Ok(pop_maze.guard_states.into_iter().map(|guard| guard.pos).collect())
+}
+
+/// Count of positions on the original path where a new obstacle would trap the guard in a loop.
+#[instrument(skip_all)]
+fn count_loop_obstacles(
+        maze: &Maze,
+        guard_initial: Guard,
+        mut original_path_positions: HashSet<Point2D>,
+) -> Result<usize> {
+        let (pop_maze_base, starting_position) =
+                (PopulatedMazeWHSet::new(maze.clone(), guard_initial)?, guard_initial.pos);
         // remove starting position from set
         original_path_positions.remove(&starting_position);
         let pos_to_loop_sum = original_path_positions
@@ -191,6 +214,7 @@ impl std::fmt::Display for PopulatedMazeWHSet {
 
 #[cfg(test)]
 mod tests {
+        use aoc_core::{InputKind, Part, Solution as _};
         // use indoc::indoc;
         // use quickcheck::TestResult;
         // use quickcheck_macros::quickcheck;
@@ -200,20 +224,31 @@ mod tests {
         use tracing::instrument;
 
         use super::*;
-        use crate::{EXAMPLE_INPUT, FINAL_INPUT};
+        use crate::{Day06, EXAMPLE_INPUT, FINAL_INPUT};
 
         #[test]
         #[instrument]
         fn test_process_example() -> Result<()> {
                 let input = EXAMPLE_INPUT;
-                let expected = 6;
-                assert_eq!(process_part2(input)?, expected);
+                let expected = Day06::recorded_answer(Part::Part2, InputKind::Example, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
+                Ok(())
+        }
+
+        #[test]
+        #[instrument]
+        fn test_solve_both_example() -> Result<()> {
+                let (maze, guard) = parse_input_with_guard(EXAMPLE_INPUT)?;
+                let expected = [Part::Part1, Part::Part2]
+                        .map(|part| Day06::recorded_answer(part, InputKind::Example, EXAMPLE_INPUT));
+                let (part1, part2) = solve_both(&maze, guard)?;
+                assert_eq!([part1.to_string(), part2.to_string()], expected);
                 Ok(())
         }
 
         #[test]
+        #[ignore = "needs the private full input; run with `just test-full`"]
         // #[cfg_attr(test, optimize(speed))] // optimize for speed <-- experimental feature
-        #[cfg_attr(debug_assertions, ignore = "Too slow in debug mode")]
         #[instrument]
         fn test_process_problem_input() -> Result<()> {
                 tracing::event![
@@ -221,9 +256,9 @@ mod tests {
                         "Warn: This test's code is about 1_000x *slower* in debug mode. (largely due to tracing)"
                 ];
 
-                let input = FINAL_INPUT;
-                let expected = 1_562;
-                assert_eq!(process_part2(input)?, expected);
+                let input = &FINAL_INPUT.load()?;
+                let expected = Day06::recorded_answer(Part::Part2, InputKind::Full, input);
+                assert_eq!(process_part2(input)?.to_string(), expected);
                 Ok(())
         }
 }
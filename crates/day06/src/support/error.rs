@@ -106,6 +106,8 @@ pub enum ErrKindDay06 {
         Clap { source: clap::Error },
         #[display("Error with tracing_subscriber::EnvFilter parsing env directive: {}", source)]
         EnvError { source: tracing_subscriber::filter::FromEnvError },
+        #[display("input error: {}", source)]
+        Input { source: aoc_core::Error },
         #[display("io error: {}", source)]
         Io { source: io::Error },
         #[display("Error setting tracing subscriber default: {}", source)]
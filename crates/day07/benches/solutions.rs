@@ -0,0 +1,19 @@
+//! Benchmarks of Day07's parsing & solutions, on its example & full inputs. (`cargo bench -p day07`)
+//!
+//! Full inputs are read from the input directory; a part whose full input is missing is only benchmarked on its
+//! example. `cargo xtask bench` keeps the results as JSON baselines, and reports regressions between runs.
+
+use aoc_core::{BenchInput, Part, bench_inputs};
+use day07::{Day07, parse_input};
+use divan::{Bencher, black_box};
+
+fn main() {
+        divan::main();
+}
+
+#[divan::bench(args = bench_inputs::<Day07>(Part::Part1))]
+fn parse(bencher: Bencher, input: &BenchInput) {
+        bencher.bench(|| parse_input(black_box(&input.text)));
+}
+
+// `process_part1` & `process_part2` are benchmarked once they are solved; until then they panic (`todo!`).
@@ -5,11 +5,50 @@ mod part1_lib;
 mod part2_lib;
 mod support;
 
-pub use parse::parse_input;
-pub use part1_lib::process_part1;
-pub use part2_lib::process_part2;
+use aoc_core::{InputKind, InputSource, Part, Solution};
+pub use parse::{EquationUncertain, parse_input};
+pub use part1_lib::{process_part1, solve_part1};
+pub use part2_lib::{process_part2, solve_part2};
 pub use support::{Error, Result, activate_global_default_tracing_subscriber};
 
-pub const FINAL_INPUT: &str = include_str!("../data/final_input.txt");
 pub const EXAMPLE_INPUT: &str = include_str!("../data/example_input.txt");
 pub const CUSTOM_INPUT: &str = include_str!("../data/custom_input.txt");
+/// Full puzzle input: read at runtime from the input directory, or embedded with the `embed-inputs` feature.
+#[cfg(not(feature = "embed-inputs"))]
+pub const FINAL_INPUT: InputSource = InputSource::File { day: 7, name: "final_input.txt" };
+#[cfg(feature = "embed-inputs")]
+pub const FINAL_INPUT: InputSource = InputSource::Embedded(include_str!("../../../data/no-sync/day07/final_input.txt"));
+pub const ANSWERS: &str = include_str!("../data/answers.toml");
+
+/// Day07, as registered with the workspace `aoc` runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Day07;
+impl Solution for Day07 {
+        type Answer = u64;
+        type Error = Error;
+        type Parsed = Vec<EquationUncertain>;
+
+        const ANSWERS: &'static str = ANSWERS;
+        const DAY: u8 = 7;
+        const TITLE: &'static str = "Bridge Repair";
+
+        fn input(kind: InputKind, _part: Part) -> Option<InputSource> {
+                match kind {
+                        InputKind::Example => Some(InputSource::Embedded(EXAMPLE_INPUT)),
+                        InputKind::Full => Some(FINAL_INPUT),
+                        InputKind::Custom => Some(InputSource::Embedded(CUSTOM_INPUT)),
+                }
+        }
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+                parse_input(input)
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Self::Answer> {
+                solve_part1(parsed)
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Self::Answer> {
+                solve_part2(parsed)
+        }
+}
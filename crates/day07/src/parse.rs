@@ -14,7 +14,7 @@
 
 use derive_more::derive::{Display, Index};
 // use derive_more::derive::{Constructor, Deref, DerefMut, From, Into};
-use tracing::{Level, instrument};
+use tracing::instrument;
 
 use crate::{Result, support::error::ErrKindDay07};
 
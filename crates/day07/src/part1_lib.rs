@@ -2,13 +2,19 @@
 
 use tracing::{self as tea, Level, instrument};
 
-use crate::{Result, parse::parse_input};
+use crate::{Result,
+            parse::{EquationUncertain, parse_input}};
 
 #[instrument(skip_all, ret(level = Level::DEBUG))]
 pub fn process_part1(input: &str) -> Result<u64> {
         tea::trace!(%input);
-        let _parsed_input = parse_input(input)?;
-        tea::info!(?_parsed_input);
+        let parsed_input = parse_input(input)?;
+        solve_part1(&parsed_input)
+}
+
+#[instrument(skip_all, ret(level = Level::DEBUG))]
+pub fn solve_part1(equations: &[EquationUncertain]) -> Result<u64> {
+        tea::info!(?equations);
 
         todo!();
 }
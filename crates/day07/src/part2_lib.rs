@@ -2,13 +2,18 @@
 
 use tracing::{Level, instrument};
 
-#[expect(unused)]
-use crate::{Result, parse::parse_input};
+use crate::{Result,
+            parse::{EquationUncertain, parse_input}};
 
 #[instrument(skip_all, ret(level = Level::DEBUG))]
 pub fn process_part2(input: &str) -> Result<u64> {
         tracing::event!(Level::TRACE, %input);
-        // let _parsed_input = parse_input(input)?;
+        let parsed_input = parse_input(input)?;
+        solve_part2(&parsed_input)
+}
+
+#[instrument(skip_all, ret(level = Level::DEBUG))]
+pub fn solve_part2(_equations: &[EquationUncertain]) -> Result<u64> {
         todo!();
 }
 
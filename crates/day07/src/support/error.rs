@@ -63,6 +63,9 @@ pub enum ErrKindDay07 {
         #[display("Error with tracing_subscriber::EnvFilter parsing env directive: {}", source)]
         EnvError { source: tracing_subscriber::filter::FromEnvError },
 
+        #[display("input error: {}", source)]
+        Input { source: aoc_core::Error },
+
         #[display("io error: {}", source)]
         Io { source: io::Error },
 
@@ -0,0 +1,354 @@
+//! `cargo xtask bench [N…]`: run the days' `divan` benchmarks, keep the results as JSON, and report regressions.
+//!
+//! Each run is written to `.output/benchmarks/latest.json` and compared, by median, with `baseline.json`: a median
+//! slower than the baseline's by more than the threshold is a regression. The first run, or a run with
+//! `--save-baseline`, becomes the baseline (for the benchmarks it ran).
+
+use std::{collections::BTreeMap,
+          fs,
+          path::Path,
+          process::{Command, Stdio}};
+
+use owo_colors::OwoColorize as _;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::{ErrKindXtask, Result},
+            workspace_dir};
+
+/// Directory of benchmark results, relative to the workspace.
+const RESULTS_DIR: &str = ".output/benchmarks";
+/// Name of each day's benchmark target. (`crates/dayNN/benches/solutions.rs`)
+const BENCH_TARGET: &str = "solutions";
+
+/// Timings of a benchmark, in nanoseconds, as `divan` reports them.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+        pub fastest_ns: f64,
+        pub slowest_ns: f64,
+        pub median_ns:  f64,
+        pub mean_ns:    f64,
+        pub samples:    u64,
+        pub iters:      u64,
+}
+
+/// Results of benchmarks, by name. (e.g. `day06/part1/full`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchResults {
+        pub benchmarks: BTreeMap<String, Timing>,
+}
+impl BenchResults {
+        /// The results at `path`; empty if there are none yet.
+        pub fn load(path: &Path) -> Result<Self> {
+                match fs::read_to_string(path) {
+                        Ok(text) => Ok(serde_json::from_str(&text)?),
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+                        Err(err) => Err(err.into()),
+                }
+        }
+
+        pub fn save(&self, path: &Path) -> Result<()> {
+                fs::create_dir_all(path.parent().expect("a file in the results dir"))?;
+                fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+                Ok(())
+        }
+}
+
+/// How a benchmark's median compares with its baseline's.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+        /// No baseline to compare with.
+        New,
+        /// Within the threshold, as a percentage change.
+        Same(f64),
+        Faster(f64),
+        Slower(f64),
+}
+
+/// Compare each result with its baseline; more than `threshold` percent either way is a change.
+pub fn compare(baseline: &BenchResults, latest: &BenchResults, threshold: f64) -> Vec<(String, Change)> {
+        latest.benchmarks
+                .iter()
+                .map(|(name, timing)| {
+                        let change = match baseline.benchmarks.get(name) {
+                                None => Change::New,
+                                Some(before) => {
+                                        let percent = (timing.median_ns / before.median_ns - 1.0) * 100.0;
+                                        match percent {
+                                                _ if percent > threshold => Change::Slower(percent),
+                                                _ if percent < -threshold => Change::Faster(percent),
+                                                _ => Change::Same(percent),
+                                        }
+                                }
+                        };
+                        (name.clone(), change)
+                })
+                .collect()
+}
+
+/// Benchmark `days` (every day with benchmarks, if none), passing `divan_args` on.
+pub fn bench(days: &[u8], save_baseline: bool, threshold: f64, divan_args: &[String]) -> Result<()> {
+        let workspace = workspace_dir();
+        let days = if days.is_empty() { benchmarked_days(&workspace)? } else { days.to_vec() };
+        let mut latest = BenchResults::default();
+        for day in days {
+                let package = format!("day{day:02}");
+                if !workspace
+                        .join("crates")
+                        .join(&package)
+                        .join("benches")
+                        .join(format!("{BENCH_TARGET}.rs"))
+                        .is_file()
+                {
+                        return Err(ErrKindXtask::NoBenchmarks { day });
+                }
+                // compiler progress is shown as it comes; `divan`'s table is read once done
+                let output = Command::new(env!("CARGO"))
+                        .args(["bench", "--package", &package, "--bench", BENCH_TARGET, "--"])
+                        .args(divan_args)
+                        .current_dir(&workspace)
+                        .stderr(Stdio::inherit())
+                        .output()?;
+                let table = String::from_utf8_lossy(&output.stdout);
+                print!("{table}");
+                if !output.status.success() {
+                        return Err(ErrKindXtask::Command {
+                                command: format!("cargo bench -p {package}"),
+                                status:  output.status,
+                        });
+                }
+                latest.benchmarks.extend(parse_divan(&table, &package));
+        }
+
+        let results_dir = workspace.join(RESULTS_DIR);
+        latest.save(&results_dir.join("latest.json"))?;
+        let baseline_path = results_dir.join("baseline.json");
+        let mut baseline = BenchResults::load(&baseline_path)?;
+        let changes = compare(&baseline, &latest, threshold);
+        print_changes(&baseline, &latest, &changes);
+
+        if save_baseline || baseline.benchmarks.is_empty() {
+                baseline.benchmarks.extend(latest.benchmarks);
+                baseline.save(&baseline_path)?;
+                println!("{} {}", "saved baseline".green(), baseline_path.display());
+        }
+        let regressions = changes
+                .iter()
+                .filter(|(_, change)| matches!(change, Change::Slower(_)))
+                .count();
+        match regressions {
+                0 => Ok(()),
+                count => Err(ErrKindXtask::Regressions { count, threshold }),
+        }
+}
+
+/// Days with a benchmark target, in calendar order.
+fn benchmarked_days(workspace: &Path) -> Result<Vec<u8>> {
+        let mut days: Vec<u8> = fs::read_dir(workspace.join("crates"))?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|dir| dir.join("benches").join(format!("{BENCH_TARGET}.rs")).is_file())
+                .filter_map(|dir| dir.file_name()?.to_str()?.strip_prefix("day")?.parse().ok())
+                .collect();
+        days.sort_unstable();
+        Ok(days)
+}
+
+fn print_changes(baseline: &BenchResults, latest: &BenchResults, changes: &[(String, Change)]) {
+        const HEADER: &str = "benchmark (median)";
+        let width = changes
+                .iter()
+                .map(|(name, _)| name.len())
+                .max()
+                .unwrap_or(0)
+                .max(HEADER.len());
+        println!("\n{HEADER:width$}  {:>10}  {:>10}  change", "baseline", "latest");
+        for (name, change) in changes {
+                let before = baseline
+                        .benchmarks
+                        .get(name)
+                        .map_or_else(|| "-".to_string(), |timing| format_ns(timing.median_ns));
+                let after = format_ns(latest.benchmarks[name].median_ns);
+                let change = match change {
+                        Change::New => "new".dimmed().to_string(),
+                        Change::Same(percent) => format!("{percent:+.1}%").dimmed().to_string(),
+                        Change::Faster(percent) => format!("{percent:+.1}% faster").green().to_string(),
+                        Change::Slower(percent) => format!("{percent:+.1}% REGRESSED").red().bold().to_string(),
+                };
+                println!("{name:width$}  {before:>10}  {after:>10}  {change}");
+        }
+}
+
+/// Timings from `divan`'s table, named `<prefix>/<benchmark>/<argument>`.
+///
+/// ```text
+/// solutions      fastest       │ slowest       │ median        │ mean          │ samples │ iters
+/// ├─ parse                     │               │               │               │         │
+/// │  ├─ example  715.6 ns      │ 5.804 µs      │ 912.1 ns      │ 1.548 µs      │ 10      │ 10
+/// ```
+pub fn parse_divan(table: &str, prefix: &str) -> BTreeMap<String, Timing> {
+        let mut results = BTreeMap::new();
+        // names of the rows above, by depth
+        let mut path: Vec<String> = Vec::new();
+        for line in table.lines().filter(|line| line.contains('│')) {
+                let tree = line
+                        .chars()
+                        .take_while(|ch| matches!(ch, '├' | '╰' | '─' | '│' | ' '))
+                        .count();
+                let depth = tree / 3;
+                let row: String = line.chars().skip(tree).collect();
+                let mut columns = row.split('│').map(str::trim);
+                let first = columns.next().unwrap_or_default();
+                let (name, fastest) = first.split_once(char::is_whitespace).unwrap_or((first, ""));
+                if depth == 0 {
+                        // the header
+                        continue;
+                }
+                path.truncate(depth - 1);
+                path.push(name.to_string());
+                let [slowest, median, mean, samples, iters] = [(); 5].map(|()| columns.next().unwrap_or_default());
+                let timing = (|| {
+                        Some(Timing {
+                                fastest_ns: parse_duration(fastest.trim())?,
+                                slowest_ns: parse_duration(slowest)?,
+                                median_ns:  parse_duration(median)?,
+                                mean_ns:    parse_duration(mean)?,
+                                samples:    samples.parse().ok()?,
+                                iters:      iters.parse().ok()?,
+                        })
+                })();
+                // group rows have no timings
+                if let Some(timing) = timing {
+                        results.insert(format!("{prefix}/{}", path.join("/")), timing);
+                }
+        }
+        results
+}
+
+/// Nanoseconds of a `divan` duration. (e.g. `5.804 µs`)
+fn parse_duration(text: &str) -> Option<f64> {
+        let (value, unit) = text.split_once(' ')?;
+        let scale = match unit {
+                "ps" => 1e-3,
+                "ns" => 1.0,
+                "µs" | "us" => 1e3,
+                "ms" => 1e6,
+                "s" => 1e9,
+                _ => return None,
+        };
+        Some(value.parse::<f64>().ok()? * scale)
+}
+
+/// A duration, in the largest unit it has a whole one of.
+fn format_ns(ns: f64) -> String {
+        match ns {
+                _ if ns >= 1e9 => format!("{:.3} s", ns / 1e9),
+                _ if ns >= 1e6 => format!("{:.3} ms", ns / 1e6),
+                _ if ns >= 1e3 => format!("{:.3} µs", ns / 1e3),
+                _ => format!("{ns:.1} ns"),
+        }
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        const TABLE: &str = indoc! {"
+                Timer precision: 27 ns
+                solutions      fastest       │ slowest       │ median        │ mean          │ samples │ iters
+                ├─ parse                     │               │               │               │         │
+                │  ├─ example  715.6 ns      │ 5.804 µs      │ 912.1 ns      │ 1.548 µs      │ 10      │ 10
+                │  ╰─ full     121.2 µs      │ 248.4 µs      │ 136.1 µs      │ 152.2 µs      │ 10      │ 10
+                ╰─ part2                     │               │               │               │         │
+                   ├─ example  1.053 µs      │ 1.4 µs        │ 1.153 µs      │ 1.189 µs      │ 10      │ 20
+                   ╰─ full     1.621 ms      │ 2.507 s       │ 169 ms        │ 184.6 ms      │ 10      │ 10
+        "};
+
+        fn timing(median_ns: f64) -> Timing {
+                Timing {
+                        fastest_ns: median_ns,
+                        slowest_ns: median_ns,
+                        median_ns,
+                        mean_ns: median_ns,
+                        samples: 1,
+                        iters: 1,
+                }
+        }
+
+        #[test]
+        fn read_divan_table() {
+                let results = parse_divan(TABLE, "day02");
+                assert_eq!(results.keys().map(String::as_str).collect::<Vec<_>>(), [
+                        "day02/parse/example",
+                        "day02/parse/full",
+                        "day02/part2/example",
+                        "day02/part2/full"
+                ]);
+                let Timing { fastest_ns, slowest_ns, median_ns, mean_ns, samples, iters } =
+                        results["day02/parse/example"];
+                let nanos = [fastest_ns, slowest_ns, median_ns, mean_ns].map(|ns| ns.round() as u64);
+                assert_eq!((nanos, samples, iters), ([716, 5804, 912, 1548], 10, 10));
+                let Timing { slowest_ns, median_ns, iters, .. } = results["day02/part2/full"];
+                assert_eq!([slowest_ns, median_ns].map(|ns| ns.round() as u64), [2_507_000_000, 169_000_000]);
+                assert_eq!(iters, 10);
+        }
+
+        #[test]
+        fn regressions_beyond_threshold() {
+                let baseline = BenchResults {
+                        benchmarks: BTreeMap::from([
+                                ("a".to_string(), timing(100.0)),
+                                ("b".to_string(), timing(100.0)),
+                                ("c".to_string(), timing(100.0)),
+                        ]),
+                };
+                let latest = BenchResults {
+                        benchmarks: BTreeMap::from([
+                                ("a".to_string(), timing(105.0)),
+                                ("b".to_string(), timing(125.0)),
+                                ("c".to_string(), timing(50.0)),
+                                ("d".to_string(), timing(1.0)),
+                        ]),
+                };
+                let changes = compare(&baseline, &latest, 10.0);
+                let names: Vec<_> = changes.iter().map(|(name, _)| name.as_str()).collect();
+                assert_eq!(names, ["a", "b", "c", "d"]);
+                let percents = changes.iter().map(|(_, change)| match change {
+                        Change::Same(percent) | Change::Faster(percent) | Change::Slower(percent) => {
+                                percent.round() as i64
+                        }
+                        Change::New => 0,
+                });
+                assert_eq!(percents.collect::<Vec<_>>(), [5, 25, -50, 0]);
+                assert!(matches!(changes.iter().map(|(_, change)| *change).collect::<Vec<_>>()[..], [
+                        Change::Same(_),
+                        Change::Slower(_),
+                        Change::Faster(_),
+                        Change::New
+                ]));
+        }
+
+        #[test]
+        fn results_round_trip() -> Result<()> {
+                let path = std::env::temp_dir()
+                        .join(format!("xtask-bench-{}", std::process::id()))
+                        .join("latest.json");
+                assert_eq!(BenchResults::load(&path)?, BenchResults::default());
+                let results = BenchResults { benchmarks: parse_divan(TABLE, "day02") };
+                results.save(&path)?;
+                assert_eq!(BenchResults::load(&path)?, results);
+                fs::remove_dir_all(path.parent().expect("in a temp dir"))?;
+                Ok(())
+        }
+
+        #[test]
+        fn durations() {
+                assert_eq!(parse_duration("715.6 ns"), Some(715.6));
+                assert_eq!(parse_duration("2 s"), Some(2e9));
+                assert_eq!(parse_duration("2 fortnights"), None);
+                assert_eq!(format_ns(912.1), "912.1 ns");
+                assert_eq!(format_ns(136_100.0), "136.100 µs");
+                assert_eq!(format_ns(2.5e9), "2.500 s");
+        }
+}
@@ -0,0 +1,100 @@
+//! Error & Result type for the workspace xtasks.
+
+use std::{io, path::PathBuf};
+
+use derive_more::{Display, Error, From};
+
+#[derive(Debug, Display, From, Error)]
+pub enum ErrKindXtask {
+        // `custom` errors //
+        #[from(ignore)]
+        #[display("Day {} is not a day of the advent calendar (1..=25)", day)]
+        DayOutOfRange { day: u8 },
+
+        #[from(ignore)]
+        #[display("Day {:02} already exists: {}", day, path.display())]
+        DayExists { day: u8, path: PathBuf },
+
+        #[from(ignore)]
+        #[display("Day {:02} has no crate yet ({}); `cargo xtask new-day {}` makes one", day, path.display(), day)]
+        NoSuchDay { day: u8, path: PathBuf },
+
+        #[from(ignore)]
+        #[display("No puzzle article in {}; is it a saved puzzle page?", path.display())]
+        NoPuzzle { path: PathBuf },
+
+        #[from(ignore)]
+        #[display("{} is the page of {}, not of day {}", path.display(), title, day)]
+        WrongPage { day: u8, title: String, path: PathBuf },
+
+        #[from(ignore)]
+        #[display("Template placeholder `{}` is unknown, in {}", placeholder, path.display())]
+        UnknownPlaceholder { placeholder: String, path: PathBuf },
+
+        #[from(ignore)]
+        #[display("Could not find {} in {}; was it edited by hand?", anchor, path.display())]
+        MissingAnchor { anchor: &'static str, path: PathBuf },
+
+        #[from(ignore)]
+        #[display("`{}` failed: {}", command, status)]
+        Command { command: String, status: std::process::ExitStatus },
+
+        #[from(ignore)]
+        #[display("`cargo build` reported no executable for {}", package)]
+        NoExecutable { package: String },
+
+        #[from(ignore)]
+        #[display(
+                "No session token: set {} (in the environment or `.env`) to the adventofcode.com `session` cookie",
+                crate::site::SESSION_ENV
+        )]
+        NoSession,
+
+        #[from(ignore)]
+        #[display("{} answered {}: {}", url, status, detail)]
+        HttpStatus { url: String, status: u16, detail: Box<str> },
+
+        #[from(ignore)]
+        #[display("Part {} is solved already: {}", part, answer)]
+        AlreadySolved { part: u8, answer: String },
+
+        #[from(ignore)]
+        #[display("The site asked to wait before answering again: {}s left", seconds)]
+        WaitFirst { seconds: u64 },
+
+        #[from(ignore)]
+        #[display("{} was already submitted for part {}: {}; not sending it again", answer, part, verdict)]
+        KnownWrong { part: u8, answer: String, verdict: crate::submit::Verdict },
+
+        #[from(ignore)]
+        #[display("{} is out of the recorded bounds for part {}: the answer is {}", answer, part, bounds)]
+        OutOfBounds { part: u8, answer: String, bounds: crate::submit::Bounds },
+
+        #[from(ignore)]
+        #[display("Could not read guess history {}: {}", path.display(), source)]
+        History { path: PathBuf, source: Box<toml::de::Error> },
+
+        #[from(ignore)]
+        #[display("Could not read answers {}: {}", path.display(), source)]
+        Answers { path: PathBuf, source: Box<toml::de::Error> },
+
+        #[from(ignore)]
+        #[display("Day {:02} has no benchmarks (`benches/solutions.rs`)", day)]
+        NoBenchmarks { day: u8 },
+
+        #[from(ignore)]
+        #[display("{} benchmark(s) regressed by more than {}%", count, threshold)]
+        Regressions { count: usize, threshold: f64 },
+
+        // `packed` errors //
+        #[display("io error: {}", source)]
+        Io { source: io::Error },
+
+        #[display("http error: {}", source)]
+        Http { source: reqwest::Error },
+
+        #[display("json error: {}", source)]
+        Json { source: serde_json::Error },
+}
+
+pub type Result<T> = std::result::Result<T, ErrKindXtask>;
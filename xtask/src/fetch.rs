@@ -0,0 +1,101 @@
+//! `cargo xtask fetch <N>`: download a day's puzzle input into the input directory, once.
+//!
+//! The input is cached as `<input dir>/dayNN/final_input.txt`, where the `aoc` runner & day binaries read it.
+//! A cached input is never downloaded again; delete the file to fetch it anew.
+
+use std::{fs,
+          path::{Path, PathBuf}};
+
+use crate::{error::Result, site::SiteClient};
+
+/// Name the runner reads a day's full input from.
+pub const INPUT_FILE: &str = "final_input.txt";
+
+/// Where a fetched input ended up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+        /// Already there; nothing was requested.
+        Cached(PathBuf),
+        Downloaded(PathBuf),
+}
+
+/// Cache path of a day's input.
+pub fn input_path(input_dir: &Path, day: u8) -> PathBuf {
+        input_dir.join(format!("day{day:02}")).join(INPUT_FILE)
+}
+
+/// Download a day's input into `input_dir`, unless it is there already.
+///
+/// The client is only made (so the session token only needed) if the input has to be downloaded.
+pub fn fetch(day: u8, input_dir: &Path, client: impl FnOnce() -> Result<SiteClient>) -> Result<Fetched> {
+        let path = input_path(input_dir, day);
+        if path.is_file() {
+                return Ok(Fetched::Cached(path));
+        }
+        let input = client()?.input(day)?;
+        let dir = path.parent().expect("joined onto the input dir");
+        fs::create_dir_all(dir)?;
+        // written whole, so an interrupted download never leaves a partial input to be taken as cached
+        let partial = dir.join(format!(".{INPUT_FILE}.partial"));
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+        Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+        use crate::{error::ErrKindXtask, site::user_agent, stand_in::StandIn};
+
+        fn scratch_dir(name: &str) -> PathBuf {
+                let dir = std::env::temp_dir().join(format!("xtask-fetch-{name}-{}", std::process::id()));
+                let _ = fs::remove_dir_all(&dir);
+                dir
+        }
+
+        #[test]
+        fn downloads_once() -> Result<()> {
+                let site = StandIn::serve(|_| (200, "1 2 3\n".to_string()));
+                let input_dir = scratch_dir("once");
+                let client = || SiteClient::new("53616c7465645f5f", &site.url);
+
+                let path = input_path(&input_dir, 6);
+                assert_eq!(fetch(6, &input_dir, client)?, Fetched::Downloaded(path.clone()));
+                assert_eq!(fs::read_to_string(&path)?, "1 2 3\n");
+                assert_eq!(fetch(6, &input_dir, client)?, Fetched::Cached(path));
+
+                let requests = site.requests();
+                assert_eq!(requests.len(), 1);
+                assert_eq!((requests[0].method.as_str(), requests[0].path.as_str()), ("GET", "/2024/day/6/input"));
+                assert_eq!(requests[0].header("cookie"), Some("session=53616c7465645f5f"));
+                assert_eq!(requests[0].header("user-agent"), Some(user_agent().as_str()));
+                fs::remove_dir_all(&input_dir)?;
+                Ok(())
+        }
+
+        #[test]
+        fn cached_input_needs_no_session() -> Result<()> {
+                let input_dir = scratch_dir("cached");
+                let path = input_path(&input_dir, 6);
+                fs::create_dir_all(path.parent().expect("has a day dir"))?;
+                fs::write(&path, "cached\n")?;
+                let no_client = || Err(ErrKindXtask::NoSession);
+                assert_eq!(fetch(6, &input_dir, no_client)?, Fetched::Cached(path));
+                fs::remove_dir_all(&input_dir)?;
+                Ok(())
+        }
+
+        #[test]
+        fn failed_download_is_not_cached() {
+                let site = StandIn::serve(|_| {
+                        (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string())
+                });
+                let input_dir = scratch_dir("failed");
+                let err = fetch(6, &input_dir, || SiteClient::new("expired", &site.url)).expect_err("status 400");
+                assert!(matches!(err, ErrKindXtask::HttpStatus { status: 400, .. }), "{err}");
+                assert!(err.to_string().contains("Please log in"));
+                assert!(!input_path(&input_dir, 6).exists());
+        }
+}
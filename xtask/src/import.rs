@@ -0,0 +1,463 @@
+//! `cargo xtask import <N> <page.html>`: a saved puzzle page, brought into the day's crate.
+//!
+//! - the puzzle's articles, as markdown, into the day's README (between `<!--puzzle:start-->` & `<!--puzzle:end-->`)
+//! - each `<pre><code>` block, as `data/example_input_N.txt` (numbered through both parts)
+//! - each part's example answer (the last emphasized `<code><em>…</em></code>` of its article) into
+//!   `data/answers.toml`, by the hash of the example it is for (the article's first block, else part one's), so it is
+//!   only expected of that input; unless recorded already
+//!
+//! The page must be day `N`'s, as its title says; another day's page is refused rather than imported over this one.
+//! Saving the page again once part two is unlocked, and importing it again, brings in part two.
+
+use std::{fs, path::Path};
+
+use aoc_core::{input_hash, normalize_input};
+use owo_colors::OwoColorize as _;
+
+use crate::{error::{ErrKindXtask, Result},
+            site::SITE_URL,
+            workspace_dir};
+
+/// Marks the start of the puzzle's section of a day's README.
+pub const PUZZLE_START: &str = "<!--puzzle:start-->";
+/// Marks the end of the puzzle's section of a day's README.
+pub const PUZZLE_END: &str = "<!--puzzle:end-->";
+
+/// A puzzle, as read from its page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+        /// As the page gives it, e.g. `Day 3: Mull It Over`.
+        pub title:    String,
+        /// One per part shown.
+        pub articles: Vec<Article>,
+}
+impl Puzzle {
+        /// Day of the advent calendar, from the title; `None` if it names none.
+        pub fn day(&self) -> Option<u8> {
+                self.title.strip_prefix("Day ")?.split_once(':')?.0.trim().parse().ok()
+        }
+
+        /// The articles, as one markdown text.
+        pub fn markdown(&self) -> String {
+                self.articles
+                        .iter()
+                        .map(|article| article.markdown.as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+        }
+
+        /// Every example block, in page order.
+        pub fn examples(&self) -> impl Iterator<Item = &str> {
+                self.articles
+                        .iter()
+                        .flat_map(|article| article.examples.iter().map(String::as_str))
+        }
+}
+
+/// A part's article.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Article {
+        pub markdown: String,
+        /// Text of each `<pre><code>` block.
+        pub examples: Vec<String>,
+        /// The last emphasized code, which is the example's answer.
+        pub answer:   Option<String>,
+}
+
+/// Each part's example answer, with the example block it is for: the part's first block, else part one's.
+pub fn example_answers(puzzle: &Puzzle) -> Vec<(u8, &str, &str)> {
+        let first = puzzle.articles.iter().find_map(|article| article.examples.first());
+        (1..).zip(&puzzle.articles)
+                .filter_map(|(part, article)| {
+                        let example = article.examples.first().or(first)?;
+                        Some((part, example.as_str(), article.answer.as_deref()?))
+                })
+                .collect()
+}
+
+/// Import a saved puzzle page into `crates/dayNN`.
+pub fn import(day: u8, page: &Path) -> Result<()> {
+        let crate_dir = workspace_dir().join("crates").join(format!("day{day:02}"));
+        if !crate_dir.is_dir() {
+                return Err(ErrKindXtask::NoSuchDay { day, path: crate_dir });
+        }
+        let puzzle = parse_page(&fs::read_to_string(page)?)
+                .ok_or_else(|| ErrKindXtask::NoPuzzle { path: page.to_path_buf() })?;
+        if puzzle.day() != Some(day) {
+                return Err(ErrKindXtask::WrongPage { day, title: puzzle.title, path: page.to_path_buf() });
+        }
+        println!("{} {}", "importing".green(), puzzle.title);
+
+        let readme = crate_dir.join("README.md");
+        let text = fs::read_to_string(&readme).unwrap_or_default();
+        fs::write(&readme, set_puzzle_section(&text, &puzzle.markdown()))?;
+        println!("{} {}", "updated".green(), readme.display());
+
+        for (idx, example) in puzzle.examples().enumerate() {
+                let path = crate_dir.join("data").join(format!("example_input_{}.txt", idx + 1));
+                if fs::read_to_string(&path).is_ok_and(|text| text == example) {
+                        println!("{} {}", "unchanged".dimmed(), path.display());
+                        continue;
+                }
+                fs::write(&path, example)?;
+                println!("{} {}", "wrote".green(), path.display());
+        }
+
+        let answers_path = crate_dir.join("data/answers.toml");
+        let mut answers = fs::read_to_string(&answers_path)?;
+        for (part, example, answer) in example_answers(&puzzle) {
+                let hash = input_hash(&normalize_input(example).0);
+                match set_example_answer(&answers, part, &hash, answer) {
+                        Some(updated) => {
+                                answers = updated;
+                                println!(
+                                        "{} part{part} example answer: {answer} (for input {hash:.12})",
+                                        "recorded".green()
+                                );
+                        }
+                        None => println!("{} part{part} example answer (already recorded)", "kept".dimmed()),
+                }
+        }
+        fs::write(&answers_path, answers)?;
+        Ok(())
+}
+
+/// The puzzle on a page; `None` if it shows no puzzle article.
+pub fn parse_page(html: &str) -> Option<Puzzle> {
+        let articles: Vec<(String, Article)> = html
+                .split("<article")
+                .skip(1)
+                .filter_map(|rest| rest.split_once('>'))
+                .filter(|(attrs, _)| attrs.contains("day-desc"))
+                .map(|(_, rest)| convert(rest.split_once("</article>").map_or(rest, |(article, _)| article)))
+                .collect();
+        let title = articles.first()?.0.clone();
+        Some(Puzzle { title, articles: articles.into_iter().map(|(_, article)| article).collect() })
+}
+
+/// Markdown of an article's HTML, with its heading, example blocks & emphasized answer.
+fn convert(html: &str) -> (String, Article) {
+        let mut out = String::new();
+        let mut heading = None;
+        let mut examples = Vec::new();
+        let mut answer = None;
+        // where each open element's markdown starts in `out`
+        let mut pre_start = None;
+        let mut code_starts = Vec::new();
+        let mut link_starts = Vec::new();
+        let mut heading_start = 0;
+
+        let mut rest = html;
+        while !rest.is_empty() {
+                let Some(open) = rest.find('<') else {
+                        push_text(&mut out, rest, pre_start.is_some());
+                        break;
+                };
+                push_text(&mut out, &rest[..open], pre_start.is_some());
+                rest = &rest[open..];
+                if let Some(after) = rest.strip_prefix("<!--") {
+                        rest = after.split_once("-->").map_or("", |(_, after)| after);
+                        continue;
+                }
+                let Some((tag, after)) = rest[1..].split_once('>') else {
+                        break;
+                };
+                rest = after;
+                let closing = tag.starts_with('/');
+                let name = tag
+                        .trim_start_matches('/')
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_lowercase();
+                match (name.as_str(), closing) {
+                        ("pre", false) => pre_start = Some(out.len()),
+                        ("pre", true) => {
+                                let mut block = out.split_off(pre_start.take().unwrap_or(out.len()));
+                                if !block.ends_with('\n') {
+                                        block.push('\n');
+                                }
+                                out.push_str(&format!("```\n{block}```\n\n"));
+                                examples.push(block);
+                        }
+                        // no markup inside example blocks
+                        _ if pre_start.is_some() => {}
+                        ("h2", false) => heading_start = out.len(),
+                        ("h2", true) => {
+                                let text = out.split_off(heading_start);
+                                let text = text
+                                        .trim_matches(|ch: char| ch == '-' || ch.is_whitespace())
+                                        .to_string();
+                                out.push_str(&format!("### {text}\n\n"));
+                                heading.get_or_insert(text);
+                        }
+                        ("p", true) | ("ul", true) => out.push_str("\n\n"),
+                        ("li", false) => out.push_str("- "),
+                        ("li", true) => out.push('\n'),
+                        ("em", _) => out.push_str("**"),
+                        ("code", false) => code_starts.push(out.len()),
+                        ("code", true) => {
+                                let inner = out.split_off(code_starts.pop().unwrap_or(out.len()));
+                                match inner.strip_prefix("**").and_then(|inner| inner.strip_suffix("**")) {
+                                        Some(emphasized) if !emphasized.is_empty() => {
+                                                out.push_str(&format!("**`{emphasized}`**"));
+                                                answer = Some(emphasized.to_string());
+                                        }
+                                        _ => out.push_str(&format!("`{inner}`")),
+                                }
+                        }
+                        ("a", false) => link_starts.push((out.len(), attribute(tag, "href"))),
+                        ("a", true) => {
+                                if let Some((start, href)) = link_starts.pop() {
+                                        let text = out.split_off(start);
+                                        match href {
+                                                Some(href) if href.starts_with('/') => {
+                                                        out.push_str(&format!("[{text}]({SITE_URL}{href})"));
+                                                }
+                                                Some(href) => out.push_str(&format!("[{text}]({href})")),
+                                                None => out.push_str(&text),
+                                        }
+                                }
+                        }
+                        _ => {}
+                }
+        }
+        let markdown = tidy(&out);
+        (heading.unwrap_or_default(), Article { markdown, examples, answer })
+}
+
+/// Add page text to the markdown: as is in example blocks, else with whitespace collapsed.
+fn push_text(out: &mut String, text: &str, preformatted: bool) {
+        let text = decode_entities(text);
+        if preformatted {
+                out.push_str(&text);
+                return;
+        }
+        let mut collapsed = String::with_capacity(text.len());
+        for (idx, word) in text.split_whitespace().enumerate() {
+                if idx > 0 {
+                        collapsed.push(' ');
+                }
+                collapsed.push_str(word);
+        }
+        let line_start = out.is_empty() || out.ends_with('\n');
+        if text.starts_with(char::is_whitespace) && !line_start && !out.ends_with(' ') {
+                out.push(' ');
+        }
+        out.push_str(&collapsed);
+        if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                out.push(' ');
+        }
+}
+
+/// A tag's attribute value, e.g. `href` of `a href="/2024/about"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+        let (_, value) = tag.split_once(&format!("{name}=\""))?;
+        Some(decode_entities(value.split_once('"')?.0))
+}
+
+fn decode_entities(text: &str) -> String {
+        text.replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'")
+                .replace("&apos;", "'")
+                .replace("&nbsp;", " ")
+                .replace("&amp;", "&")
+}
+
+/// Trailing spaces dropped (outside code blocks), and no more than one blank line in a row.
+fn tidy(markdown: &str) -> String {
+        let mut out = String::with_capacity(markdown.len());
+        let mut in_block = false;
+        let mut blank = false;
+        for line in markdown.lines() {
+                if line.starts_with("```") {
+                        in_block = !in_block;
+                }
+                let line = if in_block { line } else { line.trim_end() };
+                if line.is_empty() && !in_block {
+                        if blank || out.is_empty() {
+                                continue;
+                        }
+                        blank = true;
+                } else {
+                        blank = false;
+                }
+                out.push_str(line);
+                out.push('\n');
+        }
+        out.trim_end().to_string() + "\n"
+}
+
+/// The README with its puzzle section replaced by `markdown`, or added at the end under `## Puzzle`.
+pub fn set_puzzle_section(readme: &str, markdown: &str) -> String {
+        let section = format!("{PUZZLE_START}\n{markdown}{PUZZLE_END}");
+        if let (Some(start), Some(end)) = (readme.find(PUZZLE_START), readme.find(PUZZLE_END)) {
+                if start < end {
+                        return format!("{}{section}{}", &readme[..start], &readme[end + PUZZLE_END.len()..]);
+                }
+        }
+        let readme = readme.trim_end();
+        let gap = if readme.is_empty() { "" } else { "\n\n" };
+        format!("{readme}{gap}## Puzzle\n\n{section}\n")
+}
+
+/// The answers file with an answer for the example input of hash `input_hash`, in the part's `by_input` table; `None`
+/// if one is recorded already.
+///
+/// Recorded by input, it is only expected of a day whose example input is that block. (See [`aoc_core::Answers`].)
+pub fn set_example_answer(answers: &str, part: u8, input_hash: &str, answer: &str) -> Option<String> {
+        let header = format!("[part{part}.by_input]");
+        let key = toml::Value::String(input_hash.to_string()).to_string();
+        let entry = format!("{key} = {}", toml::Value::String(answer.to_string()));
+        let mut lines: Vec<&str> = answers.lines().collect();
+        let section_end = |start: usize| (start + 1..lines.len()).find(|&idx| lines[idx].trim_start().starts_with('['));
+
+        if let Some(start) = lines.iter().position(|line| line.trim() == header) {
+                let end = section_end(start).unwrap_or(lines.len());
+                let recorded = lines[start + 1..end]
+                        .iter()
+                        .filter_map(|line| line.split_once('='))
+                        .any(|(name, _)| name.trim() == key);
+                if recorded {
+                        return None;
+                }
+                lines.insert(start + 1, &entry);
+        } else {
+                let part_header = format!("[part{part}]");
+                let idx = match lines.iter().position(|line| line.trim() == part_header) {
+                        Some(start) => section_end(start).unwrap_or(lines.len()),
+                        None => lines.len(),
+                };
+                // keep the blank line that ends the part's section, if it has one
+                let idx = match idx.checked_sub(1) {
+                        Some(before) if idx < lines.len() && lines[before].trim().is_empty() => before,
+                        _ => idx,
+                };
+                let gap = if lines[..idx].last().is_some_and(|line| !line.trim().is_empty()) {
+                        [""].as_slice()
+                } else {
+                        &[]
+                };
+                let table = [header.as_str(), entry.as_str()];
+                lines.splice(idx..idx, gap.iter().copied().chain(table));
+        }
+        let mut out = lines.join("\n");
+        out.push('\n');
+        Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        const PAGE: &str = include_str!("../data/pages/puzzle.html");
+
+        #[test]
+        fn read_saved_page() {
+                let puzzle = parse_page(PAGE).expect("has articles");
+                assert_eq!(puzzle.title, "Day 3: Stand-In Sorting");
+                assert_eq!(puzzle.day(), Some(3));
+                assert_eq!(puzzle.examples().collect::<Vec<_>>(), ["a 3\nb 1\nc 2\n", "a 3\na 3\nb 1\n"]);
+                let answers: Vec<_> = puzzle
+                        .articles
+                        .iter()
+                        .map(|article| article.answer.as_deref())
+                        .collect();
+                assert_eq!(answers, [Some("14"), Some("7")]);
+                assert_eq!(puzzle.markdown(), indoc! {"
+                        ### Day 3: Stand-In Sorting
+
+                        The **elves** have lost the order of their sorting tickets. Each line of the input is a ticket & its `weight`:
+
+                        ```
+                        a 3
+                        b 1
+                        c 2
+                        ```
+
+                        Sort the tickets by weight, and add up `position <times> weight`:
+
+                        - `b` is first, for `1 * 1`.
+                        - `c` is second, for `2 * 2`.
+
+                        In this example, the tickets sum to **`14`**. (See [the about page](https://adventofcode.com/2024/about).)
+
+                        **What do your tickets sum to?**
+
+                        ### Part Two
+
+                        Some tickets are **doubled**:
+
+                        ```
+                        a 3
+                        a 3
+                        b 1
+                        ```
+
+                        Counting each ticket once, this example sums to `1 * 1 + 2 * 3` = **`7`**.
+                "});
+                assert_eq!(parse_page("<html><article><p>not a puzzle</p></article></html>"), None);
+        }
+
+        #[test]
+        fn puzzle_section_replaced() {
+                let readme = "# Day03 Notes\n\n## Solutions\n- Part1:\n";
+                let added = set_puzzle_section(readme, "### Day 3\n");
+                assert_eq!(
+                        added,
+                        "# Day03 Notes\n\n## Solutions\n- Part1:\n\n## Puzzle\n\n<!--puzzle:start-->\n### Day \
+                         3\n<!--puzzle:end-->\n"
+                );
+                let replaced = set_puzzle_section(&(added.clone() + "\n## After\n"), "### Day 3\n\nPart two\n");
+                assert!(replaced
+                        .contains("<!--puzzle:start-->\n### Day 3\n\nPart two\n<!--puzzle:end-->\n\n## After\n"));
+                assert_eq!(
+                        set_puzzle_section("", "### Day 3\n"),
+                        "## Puzzle\n\n<!--puzzle:start-->\n### Day 3\n<!--puzzle:end-->\n"
+                );
+        }
+
+        #[test]
+        fn example_answers_recorded_by_input() {
+                let puzzle = parse_page(PAGE).expect("has articles");
+                let examples = example_answers(&puzzle);
+                assert_eq!(examples, [(1, "a 3\nb 1\nc 2\n", "14"), (2, "a 3\na 3\nb 1\n", "7")]);
+
+                let answers = indoc! {r#"
+                        # Verified answers for Day03.
+
+                        [part1]
+                        full = "184511516"
+
+                        [part2]
+                        example = { answer = "48", input = "6499c335" }
+                "#};
+                let recorded = set_example_answer(answers, 1, "a1b2", "161").expect("not recorded for a1b2");
+                assert_eq!(recorded, indoc! {r#"
+                        # Verified answers for Day03.
+
+                        [part1]
+                        full = "184511516"
+
+                        [part1.by_input]
+                        "a1b2" = "161"
+
+                        [part2]
+                        example = { answer = "48", input = "6499c335" }
+                "#});
+                assert_eq!(set_example_answer(&recorded, 1, "a1b2", "162"), None);
+                let another = set_example_answer(&recorded, 1, "c3d4", "7").expect("not recorded for c3d4");
+                assert!(another.contains("[part1.by_input]\n\"c3d4\" = \"7\"\n\"a1b2\" = \"161\"\n"));
+                let parsed = aoc_core::Answers::from_toml(&another).expect("valid answers");
+                assert_eq!(parsed.part1.by_input.get("a1b2").map(String::as_str), Some("161"));
+                assert_eq!(
+                        set_example_answer("[part1]\n", 2, "a1b2", "7").as_deref(),
+                        Some("[part1]\n\n[part2.by_input]\n\"a1b2\" = \"7\"\n")
+                );
+        }
+}
@@ -0,0 +1,272 @@
+//! `cargo xtask log-scan <N>`: time a day's release binary at each log level, to see what tracing costs.
+//!
+//! Each level is run as `<target dir>/release/dayNN <part> <input> --log=<level> --error-log=<level>` (the latter
+//! only for days that take it), after warmup runs, and summarized as mean ± σ, min, max & speed relative to the fastest
+//! level.
+//! The markdown table is printed and written to `.output/profiling/dayNN_log_level_scan.md`.
+
+use std::{fs,
+          path::{Path, PathBuf},
+          process::{Command, Stdio},
+          time::Instant};
+
+use owo_colors::OwoColorize as _;
+use serde::Deserialize;
+
+use crate::{error::{ErrKindXtask, Result},
+            workspace_dir};
+
+/// Levels scanned, unless others are given.
+pub const LEVELS: [&str; 6] = ["trace", "debug", "info", "warn", "error", "off"];
+/// Directory the table is written to, relative to the workspace.
+const PROFILING_DIR: &str = ".output/profiling";
+
+/// What to run, and how often.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scan {
+        pub day:    u8,
+        pub part:   u8,
+        /// Input kind, as the day binary takes it. (e.g. `full`)
+        pub input:  String,
+        pub warmup: u32,
+        pub runs:   u32,
+        pub levels: Vec<String>,
+}
+
+/// Summary of a command's run times, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+        pub mean:   f64,
+        /// Sample standard deviation; zero for a single run.
+        pub stddev: f64,
+        pub min:    f64,
+        pub max:    f64,
+}
+impl Stats {
+        /// Summary of at least one time.
+        pub fn of(times: &[f64]) -> Self {
+                let count = times.len() as f64;
+                let mean = times.iter().sum::<f64>() / count;
+                let variance = match times.len() {
+                        0 | 1 => 0.0,
+                        _ => times.iter().map(|time| (time - mean).powi(2)).sum::<f64>() / (count - 1.0),
+                };
+                Stats {
+                        mean,
+                        stddev: variance.sqrt(),
+                        min: times.iter().copied().fold(f64::INFINITY, f64::min),
+                        max: times.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                }
+        }
+}
+
+/// Build the day's release binary, time it at each level, and write the table.
+pub fn log_scan(scan: &Scan) -> Result<()> {
+        let workspace = workspace_dir();
+        let package = format!("day{:02}", scan.day);
+        // cargo reports where it put the binary, wherever the target directory is configured to be
+        let output = Command::new(env!("CARGO"))
+                .args(["build", "--release", "--bin", &package, "--message-format=json-render-diagnostics"])
+                .current_dir(&workspace)
+                .stderr(Stdio::inherit())
+                .output()?;
+        if !output.status.success() {
+                return Err(ErrKindXtask::Command {
+                        command: format!("cargo build --release --bin {package}"),
+                        status:  output.status,
+                });
+        }
+        let binary = built_executable(&String::from_utf8_lossy(&output.stdout), &package)
+                .ok_or_else(|| ErrKindXtask::NoExecutable { package: package.clone() })?;
+        let error_log = takes_error_log(&binary)?;
+
+        let mut rows = Vec::with_capacity(scan.levels.len());
+        for level in &scan.levels {
+                let mut args = vec![scan.part.to_string(), scan.input.clone(), format!("--log={level}")];
+                if error_log {
+                        args.push(format!("--error-log={level}"));
+                }
+                let command = format!("{package} {}", args.join(" "));
+                println!("{} {command} ({} warmup, {} runs)", "timing".green(), scan.warmup, scan.runs);
+                for _ in 0..scan.warmup {
+                        time_run(&binary, &args, &command)?;
+                }
+                let times = (0..scan.runs)
+                        .map(|_| time_run(&binary, &args, &command))
+                        .collect::<Result<Vec<_>>>()?;
+                rows.push((command, Stats::of(&times)));
+        }
+
+        let table = markdown_table(&rows);
+        let path = workspace
+                .join(PROFILING_DIR)
+                .join(format!("{package}_log_level_scan.md"));
+        fs::create_dir_all(path.parent().expect("a file in the profiling dir"))?;
+        fs::write(&path, &table)?;
+        println!("\n{table}\n{} {}", "wrote".green(), path.display());
+        Ok(())
+}
+
+/// Path of the binary named `name`, from `cargo build --message-format=json` output.
+fn built_executable(messages: &str, name: &str) -> Option<PathBuf> {
+        #[derive(Deserialize)]
+        struct Artifact {
+                reason:     String,
+                target:     Option<Target>,
+                executable: Option<PathBuf>,
+        }
+        #[derive(Deserialize)]
+        struct Target {
+                name: String,
+        }
+        messages.lines()
+                .filter_map(|line| serde_json::from_str::<Artifact>(line).ok())
+                .filter(|artifact| artifact.reason == "compiler-artifact")
+                .filter(|artifact| artifact.target.as_ref().is_some_and(|target| target.name == name))
+                .find_map(|artifact| artifact.executable)
+}
+
+/// Whether the day binary has an `--error-log` option. (Not every day does.)
+fn takes_error_log(binary: &Path) -> Result<bool> {
+        // day binaries print their help as a (`clap`) error, so it may be on either stream
+        let help = Command::new(binary).arg("--help").output()?;
+        Ok([help.stdout, help.stderr]
+                .iter()
+                .any(|out| String::from_utf8_lossy(out).contains("--error-log")))
+}
+
+/// Seconds a run of the binary took; its output is discarded, and a failed run is an error.
+fn time_run(binary: &Path, args: &[String], command: &str) -> Result<f64> {
+        let start = Instant::now();
+        let status = Command::new(binary)
+                .args(args)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()?;
+        let elapsed = start.elapsed().as_secs_f64();
+        match status.success() {
+                true => Ok(elapsed),
+                false => Err(ErrKindXtask::Command { command: command.to_string(), status }),
+        }
+}
+
+/// Markdown table of the commands' times, laid out as `hyperfine --export-markdown` does.
+///
+/// Times are in seconds if the fastest command takes one, else milliseconds.
+pub fn markdown_table(rows: &[(String, Stats)]) -> String {
+        let Some(fastest) = rows
+                .iter()
+                .map(|(_, stats)| stats)
+                .min_by(|a, b| a.mean.total_cmp(&b.mean))
+        else {
+                return String::new();
+        };
+        let (unit, scale, precision) = if fastest.mean >= 1.0 { ("s", 1.0, 3) } else { ("ms", 1e3, 1) };
+        let header = [
+                "Command".to_string(),
+                format!("Mean [{unit}]"),
+                format!("Min [{unit}]"),
+                format!("Max [{unit}]"),
+                "Relative".to_string(),
+        ];
+        let mut cells: Vec<[String; 5]> = vec![header];
+        for (command, stats) in rows {
+                let relative = if std::ptr::eq(stats, fastest) {
+                        "1.00".to_string()
+                } else {
+                        let ratio = stats.mean / fastest.mean;
+                        let spread = ratio
+                                * ((stats.stddev / stats.mean).powi(2) + (fastest.stddev / fastest.mean).powi(2))
+                                        .sqrt();
+                        format!("{ratio:.2} ± {spread:.2}")
+                };
+                cells.push([
+                        format!("`{command}`"),
+                        format!("{:.precision$} ± {:.precision$}", stats.mean * scale, stats.stddev * scale),
+                        format!("{:.precision$}", stats.min * scale),
+                        format!("{:.precision$}", stats.max * scale),
+                        relative,
+                ]);
+        }
+
+        let widths: Vec<usize> = (0..5)
+                .map(|col| cells.iter().map(|row| row[col].chars().count()).max().unwrap_or(0))
+                .collect();
+        let line = |row: &[String; 5]| {
+                let padded: Vec<String> = row
+                        .iter()
+                        .zip(&widths)
+                        .enumerate()
+                        .map(|(col, (cell, &width))| match col {
+                                0 => format!("{cell:<width$}"),
+                                _ => format!("{cell:>width$}"),
+                        })
+                        .collect();
+                format!("| {} |\n", padded.join(" | "))
+        };
+        let rule: Vec<String> = widths
+                .iter()
+                .enumerate()
+                .map(|(col, &width)| match col {
+                        0 => format!(":{}", "-".repeat(width - 1)),
+                        _ => format!("{}:", "-".repeat(width - 1)),
+                })
+                .collect();
+        let mut table = line(&cells[0]);
+        table.push_str(&format!("| {} |\n", rule.join(" | ")));
+        cells[1..].iter().for_each(|row| table.push_str(&line(row)));
+        table
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn stats_of_runs() {
+                let stats = Stats::of(&[1.0, 2.0, 3.0]);
+                assert_eq!(stats, Stats { mean: 2.0, stddev: 1.0, min: 1.0, max: 3.0 });
+                assert_eq!(Stats::of(&[0.5]), Stats { mean: 0.5, stddev: 0.0, min: 0.5, max: 0.5 });
+        }
+
+        #[test]
+        fn table_as_hyperfine_lays_it_out() {
+                let rows = [
+                        ("day06 1 full --log=info --error-log=info".to_string(), Stats {
+                                mean:   2.5,
+                                stddev: 0.02,
+                                min:    2.49,
+                                max:    2.56,
+                        }),
+                        ("day06 1 full --log=off --error-log=off".to_string(), Stats {
+                                mean:   0.002,
+                                stddev: 0.0001,
+                                min:    0.0015,
+                                max:    0.004,
+                        }),
+                ];
+                assert_eq!(markdown_table(&rows), indoc! {"
+                        | Command                                    |     Mean [ms] | Min [ms] | Max [ms] |        Relative |
+                        | :----------------------------------------- | ------------: | -------: | -------: | --------------: |
+                        | `day06 1 full --log=info --error-log=info` | 2500.0 ± 20.0 |   2490.0 |   2560.0 | 1250.00 ± 63.29 |
+                        | `day06 1 full --log=off --error-log=off`   |     2.0 ± 0.1 |      1.5 |      4.0 |            1.00 |
+                "});
+                assert_eq!(markdown_table(&[]), "");
+        }
+
+        #[test]
+        fn executable_from_build_messages() {
+                let messages = indoc! {r#"
+                        {"reason":"compiler-artifact","target":{"name":"aoc_core"},"executable":null}
+                        {"reason":"compiler-artifact","target":{"name":"day06"},"executable":null}
+                        {"reason":"compiler-artifact","target":{"name":"day06"},"executable":"/tmp/tgt/release/day06"}
+                        {"reason":"build-finished","success":true}
+                "#};
+                assert_eq!(built_executable(messages, "day06"), Some(PathBuf::from("/tmp/tgt/release/day06")));
+                assert_eq!(built_executable(messages, "day07"), None);
+        }
+}
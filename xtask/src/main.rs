@@ -16,6 +16,34 @@
 //! Note: as long as  (1)`xtask/` is displayed prominently in root
 //!       and (2) a clap interface with command descriptions is present
 //!       then basic command discoverability should be on par with just
+//!
+//! Tasks that edit workspace files in ways `just` recipes could not easily do well live here:
+//! - `new-day`: a new day crate from the template, registered with the `aoc` runner & the README
+//! - `bench`: the days' benchmarks, kept as JSON baselines, with regressions between runs reported
+//! - `log-scan`: a day's run time at each log level, as a markdown table in `.output/profiling/`
+//! - `import`: a saved puzzle page into its day's README, example inputs & example answers
+//! - `readme`: the README's table of contents & summary table (answers, run times), rebuilt from the registered days
+//!
+//! Tasks that talk to adventofcode.com (logged in with the session token; see [`site`]):
+//! - `fetch`: download a day's input into the input directory, unless already there
+//! - `submit`: post an answer, unless the day's guess history shows it to be wrong or too soon
+
+mod bench;
+mod error;
+mod fetch;
+mod import;
+mod log_scan;
+mod new_day;
+mod readme;
+mod site;
+#[cfg(test)]
+mod stand_in;
+mod submit;
+mod summary;
+
+use std::{path::{Path, PathBuf},
+          process::ExitCode,
+          time::{SystemTime, UNIX_EPOCH}};
 
 use clap::Parser;
 use owo_colors::{self as _, OwoColorize};
@@ -28,11 +56,160 @@ enum Args {
         Hello,
         /// add two numbers
         Add { a: i32, b: i32 },
+        /// Create `crates/dayNN` from the new-day template, register it with `aoc`, and add it to the README
+        NewDay {
+                /// Day of the advent calendar.
+                day:   u8,
+                /// Puzzle title, as given on the puzzle page.
+                #[arg(long, short, default_value = "TBD")]
+                title: String,
+        },
+        /// Import a saved puzzle page into its day: the puzzle into the README, examples into `data/example_input_N.txt`,
+        /// and example answers into `data/answers.toml`
+        Import {
+                /// Day of the advent calendar.
+                day:  u8,
+                /// The puzzle's page, saved as HTML.
+                page: PathBuf,
+        },
+        /// Rebuild the README's table of contents & summary table from the `aoc` registry and the days' answers files
+        Readme,
+        /// Run the days' benchmarks, keep the results as JSON (`.output/benchmarks/`), and report regressions
+        Bench {
+                /// Days to benchmark. [default: every day with benchmarks]
+                days:          Vec<u8>,
+                /// Make this run the baseline that later runs are compared with.
+                #[arg(long)]
+                save_baseline: bool,
+                /// Slowdown of a median, in percent, that counts as a regression.
+                #[arg(long, default_value_t = 10.0)]
+                threshold:     f64,
+                /// Arguments for `divan`, after `--`. (e.g. `-- --sample-count 20 part1`)
+                #[arg(last = true)]
+                divan_args:    Vec<String>,
+        },
+        /// Time a day's release binary at each log level, writing the table to `.output/profiling/`
+        LogScan {
+                /// Day of the advent calendar.
+                day:    u8,
+                /// Part of the puzzle.
+                #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+                part:   u8,
+                /// Input to run on, as the day binary takes it.
+                #[arg(long, default_value = "full")]
+                input:  String,
+                /// Untimed runs before each level's timed runs.
+                #[arg(long, default_value_t = 1)]
+                warmup: u32,
+                /// Timed runs per level.
+                #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+                runs:   u32,
+                /// Log levels to scan, comma-separated.
+                #[arg(long, value_delimiter = ',', default_values = log_scan::LEVELS)]
+                levels: Vec<String>,
+        },
+        /// Download a day's puzzle input into the input directory; a downloaded input is never fetched again
+        Fetch {
+                /// Day of the advent calendar.
+                day:       u8,
+                /// Directory of inputs (`dayNN/final_input.txt`). [default: as configured for `aoc`]
+                #[arg(long)]
+                input_dir: Option<PathBuf>,
+        },
+        /// Submit an answer for a day's part, recording it in the day's guess history
+        Submit {
+                /// Day of the advent calendar.
+                day:       u8,
+                /// Part of the puzzle.
+                #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+                part:      u8,
+                answer:    String,
+                /// Directory of inputs, where guesses are kept (`dayNN/guesses.toml`). [default: as configured for `aoc`]
+                #[arg(long)]
+                input_dir: Option<PathBuf>,
+        },
+}
+
+/// The workspace's root directory.
+fn workspace_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("xtask is a workspace member")
+                .to_path_buf()
+}
+
+/// The day, if it is one of the advent calendar.
+fn calendar_day(day: u8) -> error::Result<u8> {
+        match day {
+                1..=25 => Ok(day),
+                _ => Err(error::ErrKindXtask::DayOutOfRange { day }),
+        }
+}
+
+/// Client for the site, logged in with the configured session token.
+fn site_client() -> error::Result<site::SiteClient> {
+        site::SiteClient::new(&site::session_token(&workspace_dir())?, site::SITE_URL)
 }
 
-fn main() {
-        match Args::parse() {
-                Args::Hello => println!("Hello, world"),
+/// `fetch`, reporting where the input is.
+fn fetch_input(day: u8, input_dir: Option<PathBuf>) -> error::Result<()> {
+        let day = calendar_day(day)?;
+        let input_dir = input_dir.unwrap_or_else(aoc_core::default_input_dir);
+        match fetch::fetch(day, &input_dir, site_client)? {
+                fetch::Fetched::Cached(path) => println!("{} {}", "cached".dimmed(), path.display()),
+                fetch::Fetched::Downloaded(path) => println!("{} {}", "downloaded".green(), path.display()),
+        }
+        Ok(())
+}
+
+/// `submit`, reporting the verdict.
+fn submit_answer(day: u8, part: u8, answer: &str, input_dir: Option<PathBuf>) -> error::Result<()> {
+        let day = calendar_day(day)?;
+        let input_dir = input_dir.unwrap_or_else(aoc_core::default_input_dir);
+        let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("after the epoch")
+                .as_secs();
+        let response = submit::submit(day, part, answer, &input_dir, now, site_client)?;
+        let verdict = response.verdict.to_string();
+        match response.verdict {
+                submit::Verdict::Correct => println!("{}", verdict.green().bold()),
+                verdict if verdict.is_wrong() => println!("{}", verdict.to_string().red().bold()),
+                _ => println!("{}", verdict.yellow().bold()),
+        }
+        println!("{}", response.message.dimmed());
+        if response.verdict == submit::Verdict::Correct {
+                println!("record it in crates/day{day:02}/data/answers.toml as part{part}'s `full` answer:");
+                let input = aoc_core::InputSource::File { day, name: "final_input.txt" }.load_from(&input_dir);
+                match input {
+                        Ok(input) => println!(
+                                "full = {{ answer = \"{answer}\", input = \"{}\" }}",
+                                aoc_core::input_hash(&input)
+                        ),
+                        Err(err) => println!("(could not hash the input: {})", err.source),
+                }
+        }
+        Ok(())
+}
+
+fn main() -> ExitCode {
+        let result = match Args::parse() {
+                Args::Hello => {
+                        println!("Hello, world");
+                        Ok(())
+                }
+                Args::NewDay { day, title } => new_day::new_day(day, &title),
+                Args::Import { day, page } => calendar_day(day).and_then(|day| import::import(day, &page)),
+                Args::Readme => summary::readme(),
+                Args::Bench { days, save_baseline, threshold, divan_args } => days
+                        .into_iter()
+                        .map(calendar_day)
+                        .collect::<error::Result<Vec<_>>>()
+                        .and_then(|days| bench::bench(&days, save_baseline, threshold, &divan_args)),
+                Args::LogScan { day, part, input, warmup, runs, levels } => calendar_day(day)
+                        .and_then(|day| log_scan::log_scan(&log_scan::Scan { day, part, input, warmup, runs, levels })),
+                Args::Fetch { day, input_dir } => fetch_input(day, input_dir),
+                Args::Submit { day, part, answer, input_dir } => submit_answer(day, part, &answer, input_dir),
                 Args::Add { a, b } => {
                         let sum = a + b;
                         let sum = sum.green();
@@ -42,6 +219,14 @@ fn main() {
                         println!("The (dec) sum of {a:>16}  and {b:>16} is {sum:>16}");
                         println!("The (oct) sum of {a:>16o}  and {b:>16o} is {sum:>16o}");
                         println!("The (bin) sum of {a:>16b}  and {b:>16b} is {sum:>16b}");
+                        Ok(())
+                }
+        };
+        match result {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                        eprintln!("{} {err}", "error:".red().bold());
+                        ExitCode::FAILURE
                 }
         }
 }
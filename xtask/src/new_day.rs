@@ -0,0 +1,315 @@
+//! `cargo xtask new-day <N>`: a new day crate from the `_template__new_day` template, registered with the runner.
+//!
+//! The template is written for `cargo generate`; its Liquid placeholders (`{{ project-name | upper_camel_case }}`,
+//! `{{ day }}`, `{{ title }}`) are rendered here directly, so neither `cargo generate` nor a prompt is needed.
+
+use std::{fs,
+          path::{Path, PathBuf},
+          process::Command};
+
+use owo_colors::OwoColorize as _;
+
+use crate::{error::{ErrKindXtask, Result},
+            readme, workspace_dir};
+
+/// Template directory, relative to the workspace.
+const TEMPLATE_DIR: &str = ".support/cargo_generate_templates/_template__new_day";
+/// Template files that configure `cargo generate`, rather than being part of the day.
+const TEMPLATE_CONFIG: &str = "cargo-generate.toml";
+
+/// Values for a template's placeholders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateVars {
+        pub day:   u8,
+        pub title: String,
+}
+impl TemplateVars {
+        /// Crate name, as `cargo generate`'s `project-name`. (e.g. `day08`)
+        pub fn project_name(&self) -> String {
+                format!("day{:02}", self.day)
+        }
+
+        fn get(&self, name: &str) -> Option<String> {
+                match name {
+                        "project-name" => Some(self.project_name()),
+                        "day" => Some(self.day.to_string()),
+                        "title" => Some(self.title.clone()),
+                        _ => None,
+                }
+        }
+}
+
+/// Create `crates/dayNN` from the template, register it with the `aoc` runner, and add it to the README.
+pub fn new_day(day: u8, title: &str) -> Result<()> {
+        new_day_in(&workspace_dir(), day, title)
+}
+
+/// As [`new_day`], in the given workspace.
+///
+/// Every edit is worked out before anything is written, so a missing anchor leaves the workspace as it was.
+fn new_day_in(workspace: &Path, day: u8, title: &str) -> Result<()> {
+        if !(1..=25).contains(&day) {
+                return Err(ErrKindXtask::DayOutOfRange { day });
+        }
+        let vars = TemplateVars { day, title: title.to_string() };
+        let crate_dir = workspace.join("crates").join(vars.project_name());
+        if crate_dir.exists() {
+                return Err(ErrKindXtask::DayExists { day, path: crate_dir });
+        }
+
+        let registry = edited(&workspace.join("crates/aoc/src/registry.rs"), |text| {
+                register_solution(text, &vars.project_name())
+        })?;
+        let manifest = edited(&workspace.join("crates/aoc/Cargo.toml"), |text| {
+                add_runner_dependency(text, &vars.project_name())
+        })?;
+        let readme_md = edited(&workspace.join("README.md"), |text| readme::add_day(text, day, title))?;
+
+        let written = render_template(&workspace.join(TEMPLATE_DIR), &crate_dir, &vars).inspect_err(|_| {
+                // a partial crate would stop the day being created again
+                let _ = fs::remove_dir_all(&crate_dir);
+        })?;
+        println!("{} {} ({} files)", "created".green(), crate_dir.display(), written.len());
+        for (path, text) in [&registry, &manifest, &readme_md] {
+                fs::write(path, text)?;
+        }
+        println!("{} {} with the aoc runner", "registered".green(), vars.project_name());
+        println!("{} {} to {}", "added".green(), vars.project_name(), readme_md.0.display());
+
+        // the template is not laid out as rustfmt would, and registering may have lengthened lines
+        let status = Command::new(env!("CARGO"))
+                .args(["fmt", "--package", &vars.project_name(), "--package", "aoc"])
+                .current_dir(workspace)
+                .status()?;
+        if !status.success() {
+                return Err(ErrKindXtask::Command { command: "cargo fmt".to_string(), status });
+        }
+        Ok(())
+}
+
+/// A file's path, and its text as rewritten by `edit`, which gives `None` if its anchor text is missing.
+fn edited(path: &Path, edit: impl FnOnce(&str) -> Option<String>) -> Result<(PathBuf, String)> {
+        let text = fs::read_to_string(path)?;
+        let edited = edit(&text).ok_or_else(|| ErrKindXtask::MissingAnchor {
+                anchor: "the place to add the day",
+                path:   path.to_path_buf(),
+        })?;
+        Ok((path.to_path_buf(), edited))
+}
+
+/// Render every file of `template_dir` into `out_dir`, returning the files written.
+pub fn render_template(template_dir: &Path, out_dir: &Path, vars: &TemplateVars) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        let mut dirs = vec![template_dir.to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+                for entry in fs::read_dir(&dir)? {
+                        let path = entry?.path();
+                        if path.is_dir() {
+                                dirs.push(path);
+                                continue;
+                        }
+                        let relative = path.strip_prefix(template_dir).expect("walked from the template dir");
+                        if relative == Path::new(TEMPLATE_CONFIG) {
+                                continue;
+                        }
+                        let text = fs::read_to_string(&path)?;
+                        let rendered = render(&text, vars).map_err(|placeholder| ErrKindXtask::UnknownPlaceholder {
+                                placeholder,
+                                path: path.clone(),
+                        })?;
+                        let out = out_dir.join(relative);
+                        fs::create_dir_all(out.parent().expect("joined onto out_dir"))?;
+                        fs::write(&out, rendered)?;
+                        written.push(out);
+                }
+        }
+        written.sort();
+        Ok(written)
+}
+
+/// Replace each `{{ name }}` or `{{ name | filter }}` in `text`; the placeholder is returned if it is unknown.
+pub fn render(text: &str, vars: &TemplateVars) -> std::result::Result<String, String> {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+                let Some(len) = rest[start..].find("}}") else {
+                        break;
+                };
+                let placeholder = &rest[start + 2..start + len];
+                let (name, filter) = match placeholder.split_once('|') {
+                        Some((name, filter)) => (name.trim(), Some(filter.trim())),
+                        None => (placeholder.trim(), None),
+                };
+                let value = vars.get(name).ok_or_else(|| placeholder.trim().to_string())?;
+                let value = match filter {
+                        None => value,
+                        Some("upper_camel_case") => upper_camel_case(&value),
+                        Some("snake_case") => value.replace('-', "_").to_lowercase(),
+                        Some("kebab_case") => value.replace('_', "-").to_lowercase(),
+                        Some(_) => return Err(placeholder.trim().to_string()),
+                };
+                out.push_str(&rest[..start]);
+                out.push_str(&value);
+                rest = &rest[start + len + 2..];
+        }
+        out.push_str(rest);
+        Ok(out)
+}
+
+/// `day08` → `Day08`, `new-day` → `NewDay`
+fn upper_camel_case(name: &str) -> String {
+        name.split(['-', '_', ' '])
+                .map(|word| {
+                        let mut chars = word.chars();
+                        chars.next()
+                                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                                .unwrap_or_default()
+                })
+                .collect()
+}
+
+/// Add `&dayNN::DayNN` to the runner's `SOLUTIONS`, keeping calendar order.
+pub fn register_solution(registry: &str, project_name: &str) -> Option<String> {
+        let start = registry.find("pub static SOLUTIONS")?;
+        let value = start + registry[start..].find('=')?;
+        let open = value + registry[value..].find("&[")?;
+        let close = open + registry[open..].find("];")?;
+        let mut entries: Vec<String> = registry[open + 2..close]
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(str::to_string)
+                .collect();
+        let entry = format!("&{project_name}::{}", upper_camel_case(project_name));
+        if !entries.contains(&entry) {
+                entries.push(entry);
+                entries.sort();
+        }
+        Some(format!("{}&[{}{}", &registry[..open], entries.join(", "), &registry[close..]))
+}
+
+/// Add the day crate as a dependency of the runner, and to its `embed-inputs` feature.
+pub fn add_runner_dependency(manifest: &str, project_name: &str) -> Option<String> {
+        let with_feature = insert_sorted_line(
+                manifest,
+                |line| line.trim_start().starts_with("\"day") && line.contains("/embed-inputs"),
+                &format!("  \"{project_name}/embed-inputs\","),
+        )?;
+        insert_sorted_line(
+                &with_feature,
+                |line| line.starts_with("day") && line.contains("path = \"../day"),
+                &format!("{project_name} = {{ path = \"../{project_name}\" }}"),
+        )
+}
+
+/// Insert `new_line` among the (sorted) lines that are `is_entry`, unless present; `None` if there are no entries.
+fn insert_sorted_line(text: &str, is_entry: impl Fn(&str) -> bool, new_line: &str) -> Option<String> {
+        let mut lines: Vec<&str> = text.lines().collect();
+        if lines.contains(&new_line) {
+                return Some(text.to_string());
+        }
+        let entries: Vec<usize> = (0..lines.len()).filter(|&idx| is_entry(lines[idx])).collect();
+        let idx = entries
+                .iter()
+                .copied()
+                .find(|&idx| lines[idx] > new_line)
+                .unwrap_or(*entries.last()? + 1);
+        lines.insert(idx, new_line);
+        let mut out = lines.join("\n");
+        if text.ends_with('\n') {
+                out.push('\n');
+        }
+        Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn vars() -> TemplateVars {
+                TemplateVars { day: 8, title: "Resonant Collinearity".to_string() }
+        }
+
+        #[test]
+        fn render_placeholders() {
+                let rendered = render(
+                        "pub struct {{ project-name | upper_camel_case }}; // {{project-name}}, {{ day }}: {{ title }}",
+                        &vars(),
+                );
+                assert_eq!(rendered.as_deref(), Ok("pub struct Day08; // day08, 8: Resonant Collinearity"));
+                assert_eq!(render("{{ project-name | snake_case }}::{{", &vars()).as_deref(), Ok("day08::{{"));
+                assert_eq!(render("{{ crate_name }}", &vars()), Err("crate_name".to_string()));
+                assert_eq!(render("{{ day | shout }}", &vars()), Err("day | shout".to_string()));
+        }
+
+        #[test]
+        fn render_day_template() -> Result<()> {
+                let out_dir = std::env::temp_dir().join(format!("xtask-new-day-{}", std::process::id()));
+                let written = render_template(&workspace_dir().join(TEMPLATE_DIR), &out_dir, &vars())?;
+                assert!(written.contains(&out_dir.join("src/main.rs")));
+                assert!(written.contains(&out_dir.join("data/example_input.txt")));
+                assert!(!written.contains(&out_dir.join(TEMPLATE_CONFIG)));
+                let lib = fs::read_to_string(out_dir.join("src/lib.rs"))?;
+                assert!(lib.contains("pub struct Day08;"));
+                assert!(lib.contains("const TITLE: &'static str = \"Resonant Collinearity\";"));
+                for path in &written {
+                        assert!(!fs::read_to_string(path)?.contains("{{"), "{}", path.display());
+                }
+                fs::remove_dir_all(&out_dir)?;
+                Ok(())
+        }
+
+        #[test]
+        fn missing_anchor_writes_nothing() -> Result<()> {
+                let workspace = std::env::temp_dir().join(format!("xtask-new-day-anchor-{}", std::process::id()));
+                let _ = fs::remove_dir_all(&workspace);
+                let registry = "pub static SOLUTIONS: &[&dyn DynSolution] = &[&day01::Day01];\n";
+                fs::create_dir_all(workspace.join("crates/aoc/src"))?;
+                fs::write(workspace.join("crates/aoc/src/registry.rs"), registry)?;
+                fs::write(
+                        workspace.join("crates/aoc/Cargo.toml"),
+                        "[dependencies]\nday01 = { path = \"../day01\" }\n",
+                )?;
+                fs::write(workspace.join("README.md"), "# Advent of Code 2024\n")?;
+
+                let err = new_day_in(&workspace, 8, "Resonant Collinearity").expect_err("README has no contents");
+                assert!(matches!(err, ErrKindXtask::MissingAnchor { .. }), "{err}");
+                assert!(!workspace.join("crates/day08").exists());
+                assert_eq!(fs::read_to_string(workspace.join("crates/aoc/src/registry.rs"))?, registry);
+                fs::remove_dir_all(&workspace)?;
+                Ok(())
+        }
+
+        #[test]
+        fn register_in_order() {
+                let registry =
+                        "pub static SOLUTIONS: &[&dyn DynSolution] =\n        &[&day01::Day01, &day09::Day09];\n";
+                assert_eq!(
+                        register_solution(registry, "day08").as_deref(),
+                        Some(
+                                "pub static SOLUTIONS: &[&dyn DynSolution] =\n        &[&day01::Day01, &day08::Day08, &day09::Day09];\n"
+                        )
+                );
+                // as laid out by rustfmt once long
+                let registry = "pub static SOLUTIONS: &[&dyn DynSolution] = &[\n        &day01::Day01,\n];\n";
+                assert_eq!(
+                        register_solution(registry, "day08").as_deref(),
+                        Some("pub static SOLUTIONS: &[&dyn DynSolution] = &[&day01::Day01, &day08::Day08];\n")
+                );
+                assert_eq!(register_solution("", "day08"), None);
+        }
+
+        #[test]
+        fn runner_dependency() {
+                let manifest = "[features]\nembed-inputs = [\n  \"day01/embed-inputs\",\n  \"day09/embed-inputs\",\n]\n\n\
+                                [dependencies]\naoc_core.workspace = true\nday01 = { path = \"../day01\" }\n\n[dev-dependencies]\n";
+                let expected = "[features]\nembed-inputs = [\n  \"day01/embed-inputs\",\n  \"day08/embed-inputs\",\n  \
+                                \"day09/embed-inputs\",\n]\n\n[dependencies]\naoc_core.workspace = true\nday01 = { path = \
+                                \"../day01\" }\nday08 = { path = \"../day08\" }\n\n[dev-dependencies]\n";
+                let added = add_runner_dependency(manifest, "day08");
+                assert_eq!(added.as_deref(), Some(expected));
+                assert_eq!(add_runner_dependency(expected, "day08").as_deref(), Some(expected));
+        }
+}
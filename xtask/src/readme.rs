@@ -0,0 +1,268 @@
+//! Edits to the workspace README: its table of contents (between `<!--toc:start-->` & `<!--toc:end-->`), its summary
+//! table (between `<!--summary:start-->` & `<!--summary:end-->`), and its `## Day N: …` sections.
+
+/// Marks the start of the README's table of contents.
+pub const TOC_START: &str = "<!--toc:start-->";
+/// Marks the end of the README's table of contents.
+pub const TOC_END: &str = "<!--toc:end-->";
+/// Marks the start of the README's summary table.
+pub const SUMMARY_START: &str = "<!--summary:start-->";
+/// Marks the end of the README's summary table.
+pub const SUMMARY_END: &str = "<!--summary:end-->";
+/// Heading of the section holding the summary table.
+const SUMMARY_HEADING: &str = "Summary";
+const TOC_ENTRY: &str = "  - [Day ";
+const SECTION: &str = "## Day ";
+
+/// Heading of a day's README section, linking the puzzle & the day's own notes.
+pub fn day_heading(day: u8, title: &str) -> String {
+        format!(
+                "Day {day}: [{title}](https://adventofcode.com/2024/day/{day}) : [code takeaways](crates/day{day:02}/README.md)"
+        )
+}
+
+/// Anchor of a heading, as Markdown renderers (and the TOC's generator) derive it.
+///
+/// Lowercased; characters other than letters, digits, `-` & `_` dropped; runs of whitespace become one `-`.
+pub fn anchor(heading: &str) -> String {
+        heading.to_lowercase()
+                .chars()
+                .filter(|&ch| ch.is_alphanumeric() || ch.is_whitespace() || ch == '-' || ch == '_')
+                .collect::<String>()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join("-")
+}
+
+/// Day of a TOC entry (`  - [Day N: …`) or section heading (`## Day N: …`), if the line is one.
+fn day_of(line: &str, prefix: &str) -> Option<u8> {
+        line.strip_prefix(prefix)?.split_once(':')?.0.parse().ok()
+}
+
+/// Heading of the day's section, as it stands, if it has one.
+fn section_heading(lines: &[String], day: u8) -> Option<String> {
+        lines.iter()
+                .find(|line| day_of(line, SECTION) == Some(day))
+                .map(|line| line["## ".len()..].to_string())
+}
+
+/// Add a day to the TOC & give it a section, each in calendar order; parts already present are left as they are.
+///
+/// The TOC entry links the day's section heading as it stands, if it has one.
+///
+/// `None` if the README has no table of contents.
+pub fn add_day(readme: &str, day: u8, title: &str) -> Option<String> {
+        let mut lines: Vec<String> = readme.lines().map(str::to_string).collect();
+        let toc_end = lines.iter().position(|line| line == TOC_END)?;
+
+        if !lines[..toc_end].iter().any(|line| day_of(line, TOC_ENTRY) == Some(day)) {
+                let heading = section_heading(&lines, day).unwrap_or_else(|| day_heading(day, title));
+                let entry = format!("{TOC_ENTRY}{day}: {title}](#{})", anchor(&heading));
+                let idx = (0..toc_end)
+                        .find(|&idx| day_of(&lines[idx], TOC_ENTRY).is_some_and(|other| other > day))
+                        .unwrap_or(toc_end);
+                lines.insert(idx, entry);
+        }
+
+        if !lines.iter().any(|line| day_of(line, SECTION) == Some(day)) {
+                let section = [format!("## {}", day_heading(day, title)), "-".to_string(), String::new()];
+                match lines
+                        .iter()
+                        .position(|line| day_of(line, SECTION).is_some_and(|other| other > day))
+                {
+                        Some(idx) => {
+                                lines.splice(idx..idx, section);
+                        }
+                        None => {
+                                if lines.last().is_some_and(|line| !line.is_empty()) {
+                                        lines.push(String::new());
+                                }
+                                lines.extend(section);
+                                lines.pop();
+                        }
+                }
+        }
+
+        let mut out = lines.join("\n");
+        out.push('\n');
+        Some(out)
+}
+
+/// Days with a `## Day N: …` section, in the README's order.
+pub fn section_days(readme: &str) -> Vec<u8> {
+        readme.lines().filter_map(|line| day_of(line, SECTION)).collect()
+}
+
+/// The table of contents rebuilt from `days` (day & title, in order): its top-level entries kept, then the summary, then
+/// each day, linking its section. (A day without a section is linked to where [`add_day`] would put one.)
+///
+/// `None` if the README has no table of contents.
+pub fn set_toc(readme: &str, days: &[(u8, &str)]) -> Option<String> {
+        let mut lines: Vec<String> = readme.lines().map(str::to_string).collect();
+        let start = lines.iter().position(|line| line == TOC_START)?;
+        let end = start + lines[start..].iter().position(|line| line == TOC_END)?;
+
+        let mut toc: Vec<String> = lines[start + 1..end]
+                .iter()
+                .filter(|line| line.starts_with("- "))
+                .cloned()
+                .collect();
+        if lines.iter().any(|line| line == SUMMARY_START) {
+                toc.push(format!("  - [{SUMMARY_HEADING}](#{})", anchor(SUMMARY_HEADING)));
+        }
+        for &(day, title) in days {
+                let heading = section_heading(&lines, day).unwrap_or_else(|| day_heading(day, title));
+                toc.push(format!("{TOC_ENTRY}{day}: {title}](#{})", anchor(&heading)));
+        }
+        lines.splice(start + 1..end, toc);
+
+        let mut out = lines.join("\n");
+        out.push('\n');
+        Some(out)
+}
+
+/// The summary table set to `table`, in a section of its own after the table of contents if there is none yet.
+///
+/// `None` if the README has neither a summary table nor a table of contents.
+pub fn set_summary(readme: &str, table: &str) -> Option<String> {
+        let mut lines: Vec<String> = readme.lines().map(str::to_string).collect();
+        let table = table.lines().map(str::to_string);
+        match (lines.iter().position(|line| line == SUMMARY_START), lines.iter().position(|line| line == SUMMARY_END)) {
+                (Some(start), Some(end)) if start < end => {
+                        lines.splice(start + 1..end, table);
+                }
+                _ => {
+                        let toc_end = lines.iter().position(|line| line == TOC_END)?;
+                        let section = [String::new(), format!("## {SUMMARY_HEADING}"), SUMMARY_START.to_string()]
+                                .into_iter()
+                                .chain(table)
+                                .chain([SUMMARY_END.to_string()]);
+                        lines.splice(toc_end + 1..toc_end + 1, section);
+                }
+        }
+
+        let mut out = lines.join("\n");
+        out.push('\n');
+        Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn anchors_match_existing_toc() {
+                let heading = "Day 1: [Historian Hysteria](https://adventofcode.com/2024/day/1) : [code takeaways](day01/README.md)";
+                assert_eq!(
+                        anchor(heading),
+                        "day-1-historian-hysteriahttpsadventofcodecom2024day1-code-takeawaysday01readmemd"
+                );
+                assert_eq!(anchor("Advent of Code 2024"), "advent-of-code-2024");
+        }
+
+        #[test]
+        fn add_day_in_order() {
+                let readme = indoc! {"
+                        # AoC
+                        <!--toc:start-->
+                          - [Day 1: One](#day-1-one)
+                          - [Day 9: Nine](#day-9-nine)
+                        <!--toc:end-->
+
+                        ## Day 1: One
+                        - notes
+
+                        ## Day 9: Nine
+                        - notes
+                "};
+                let added = add_day(readme, 8, "Eight").expect("has a TOC");
+                let heading = day_heading(8, "Eight");
+                let expected = format!(
+                        indoc! {"
+                        # AoC
+                        <!--toc:start-->
+                          - [Day 1: One](#day-1-one)
+                          - [Day 8: Eight](#{anchor})
+                          - [Day 9: Nine](#day-9-nine)
+                        <!--toc:end-->
+
+                        ## Day 1: One
+                        - notes
+
+                        ## {heading}
+                        -
+
+                        ## Day 9: Nine
+                        - notes
+                "},
+                        anchor = anchor(&heading),
+                        heading = heading
+                );
+                assert_eq!(added, expected);
+                assert_eq!(add_day(&added, 8, "Eight").as_deref(), Some(expected.as_str()));
+
+                let appended = add_day(readme, 10, "Ten").expect("has a TOC");
+                assert!(appended.ends_with("- notes\n\n## Day 10: [Ten](https://adventofcode.com/2024/day/10) : [code takeaways](crates/day10/README.md)\n-\n"));
+                assert_eq!(add_day("# no toc\n", 8, "Eight"), None);
+
+                // a section already there is linked as titled, whatever the title given
+                let untocced = readme.replace("  - [Day 9: Nine](#day-9-nine)\n", "");
+                let relinked = add_day(&untocced, 9, "Nine, Edited").expect("has a TOC");
+                assert!(relinked.contains("  - [Day 9: Nine, Edited](#day-9-nine)\n<!--toc:end-->"));
+        }
+
+        #[test]
+        fn toc_and_summary_rebuilt() {
+                let readme = indoc! {"
+                        # AoC
+                        <!--toc:start-->
+                        - [AoC](#aoc)
+                          - [Day 1: One](#day-1-one)
+                          - [Day 9: Nine](#day-9-nine)
+                        <!--toc:end-->
+
+                        ## Day 1: One
+                        - notes
+
+                        ## Day 9: Nine
+                        - notes
+                "};
+                let summarized = set_summary(readme, "| Day |\n| --: |\n| 1 |\n").expect("has a TOC");
+                let rebuilt = set_toc(&summarized, &[(1, "One"), (2, "Two")]).expect("has a TOC");
+                let expected = format!(
+                        indoc! {"
+                        # AoC
+                        <!--toc:start-->
+                        - [AoC](#aoc)
+                          - [Summary](#summary)
+                          - [Day 1: One](#day-1-one)
+                          - [Day 2: Two](#{anchor})
+                        <!--toc:end-->
+
+                        ## Summary
+                        <!--summary:start-->
+                        | Day |
+                        | --: |
+                        | 1 |
+                        <!--summary:end-->
+
+                        ## Day 1: One
+                        - notes
+
+                        ## Day 9: Nine
+                        - notes
+                "},
+                        anchor = anchor(&day_heading(2, "Two"))
+                );
+                assert_eq!(rebuilt, expected);
+                assert_eq!(section_days(&rebuilt), [1, 9]);
+
+                let resummarized = set_summary(&rebuilt, "| Day |\n| --: |\n| 1 |\n| 2 |\n").expect("has a summary");
+                assert!(resummarized.contains("| 1 |\n| 2 |\n<!--summary:end-->\n\n## Day 1"));
+                assert_eq!(set_toc(&resummarized, &[(1, "One"), (2, "Two")]), Some(resummarized.clone()));
+                assert_eq!(set_summary("# no toc\n", "| Day |\n"), None);
+        }
+}
@@ -0,0 +1,135 @@
+//! Talking to adventofcode.com: the session token, and a client that identifies itself as the site asks.
+//!
+//! The session token is the value of the site's `session` cookie, copied from a logged-in browser. It is read from
+//! [`SESSION_ENV`], else from the workspace's `.env` file. (See `.support/_template.env`.)
+
+use std::{fs, path::Path, time::Duration};
+
+use reqwest::{blocking::{Client, Response},
+              header::{self, HeaderMap, HeaderValue}};
+
+use crate::error::{ErrKindXtask, Result};
+
+/// Environment variable (or `.env` key) holding the session token.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// The site, unless a stand-in is given.
+pub const SITE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+/// User-Agent for every request, naming this tool and where to find it, as the site asks of automated tools.
+pub fn user_agent() -> String {
+        format!("{}/{} (+{})", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), env!("CARGO_PKG_REPOSITORY"))
+}
+
+/// The session token: [`SESSION_ENV`] if set, else its value in `<workspace>/.env`.
+pub fn session_token(workspace: &Path) -> Result<String> {
+        std::env::var(SESSION_ENV)
+                .ok()
+                .or_else(|| dotenv_value(&fs::read_to_string(workspace.join(".env")).ok()?, SESSION_ENV))
+                .map(|token| token.trim().to_string())
+                .filter(|token| !token.is_empty())
+                .ok_or(ErrKindXtask::NoSession)
+}
+
+/// Value of `key` in `.env` text, the last one set wins. (`KEY=value`, optionally `export`ed and quoted; `#` starts a comment line.)
+pub fn dotenv_value(text: &str, key: &str) -> Option<String> {
+        text.lines()
+                .map(str::trim)
+                .filter(|line| !line.starts_with('#'))
+                .filter_map(|line| line.strip_prefix("export ").unwrap_or(line).split_once('='))
+                .filter(|(name, _)| name.trim() == key)
+                .map(|(_, value)| {
+                        let value = value.trim();
+                        let unquoted = [('"', '"'), ('\'', '\'')]
+                                .iter()
+                                .find_map(|&(open, close)| value.strip_prefix(open)?.strip_suffix(close));
+                        unquoted.unwrap_or(value).to_string()
+                })
+                .next_back()
+}
+
+/// Client for the site, logged in with a session token.
+#[derive(Debug)]
+pub struct SiteClient {
+        http:     Client,
+        base_url: String,
+}
+impl SiteClient {
+        /// Client for `base_url` (normally [`SITE_URL`]).
+        pub fn new(session: &str, base_url: &str) -> Result<Self> {
+                let mut cookie =
+                        HeaderValue::from_str(&format!("session={session}")).map_err(|_| ErrKindXtask::NoSession)?;
+                cookie.set_sensitive(true);
+                let http = Client::builder()
+                        .user_agent(user_agent())
+                        .default_headers(HeaderMap::from_iter([(header::COOKIE, cookie)]))
+                        .timeout(Duration::from_secs(30))
+                        .build()?;
+                Ok(Self { http, base_url: base_url.trim_end_matches('/').to_string() })
+        }
+
+        /// URL of a day's puzzle page; other pages of the day are under it.
+        pub fn day_url(&self, day: u8) -> String {
+                format!("{}/{YEAR}/day/{day}", self.base_url)
+        }
+
+        /// A day's puzzle input.
+        pub fn input(&self, day: u8) -> Result<String> {
+                let url = format!("{}/input", self.day_url(day));
+                Ok(checked(self.http.get(&url).send()?, &url)?.text()?)
+        }
+
+        /// Post an answer for a part, returning the response page.
+        pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<String> {
+                let url = format!("{}/answer", self.day_url(day));
+                let level = part.to_string();
+                let form = [("level", level.as_str()), ("answer", answer)];
+                Ok(checked(self.http.post(&url).form(&form).send()?, &url)?.text()?)
+        }
+}
+
+/// The response, if successful; else an error with its status and the start of its body.
+fn checked(response: Response, url: &str) -> Result<Response> {
+        let status = response.status();
+        if status.is_success() {
+                return Ok(response);
+        }
+        let body = response.text().unwrap_or_default();
+        let detail: String = body
+                .trim()
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take(120)
+                .collect();
+        Err(ErrKindXtask::HttpStatus { url: url.into(), status: status.as_u16(), detail: detail.into() })
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn dotenv_values() {
+                let dotenv = indoc! {r#"
+                        # AOC_SESSION='commented out'
+                        AOC_INPUT_DIR='data/no-sync'
+                        export AOC_SESSION="53616c7465645f5f"
+                        EMPTY=
+                "#};
+                assert_eq!(dotenv_value(dotenv, SESSION_ENV).as_deref(), Some("53616c7465645f5f"));
+                assert_eq!(dotenv_value(dotenv, "AOC_INPUT_DIR").as_deref(), Some("data/no-sync"));
+                assert_eq!(dotenv_value(dotenv, "EMPTY").as_deref(), Some(""));
+                assert_eq!(dotenv_value(dotenv, "MISSING"), None);
+        }
+
+        #[test]
+        fn user_agent_names_the_repository() {
+                assert!(user_agent().starts_with("xtask/"));
+                assert!(user_agent().contains("github.com"));
+        }
+}
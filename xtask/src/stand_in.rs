@@ -0,0 +1,95 @@
+//! A local stand-in for adventofcode.com, for tests: answers each request on a loopback port with a canned response,
+//! and records the requests it was sent.
+
+use std::{io::{BufRead as _, BufReader, Read as _, Write as _},
+          net::{TcpListener, TcpStream},
+          sync::{Arc, Mutex},
+          thread};
+
+/// A request, as the stand-in received it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+        pub method:  String,
+        pub path:    String,
+        /// Header names are lowercase.
+        pub headers: Vec<(String, String)>,
+        pub body:    String,
+}
+impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+                self.headers
+                        .iter()
+                        .find(|(header, _)| header == name)
+                        .map(|(_, value)| value.as_str())
+        }
+}
+
+/// Gives the status & body to answer a request with.
+type Responder = dyn Fn(&Request) -> (u16, String) + Send + Sync;
+
+/// A stand-in server, running until the test process ends.
+pub struct StandIn {
+        /// Base URL to point a client at. (`http://127.0.0.1:<port>`)
+        pub url:  String,
+        requests: Arc<Mutex<Vec<Request>>>,
+}
+impl StandIn {
+        /// Serve every request with `respond`.
+        pub fn serve(respond: impl Fn(&Request) -> (u16, String) + Send + Sync + 'static) -> Self {
+                let listener = TcpListener::bind("127.0.0.1:0").expect("a loopback port is free");
+                let url = format!("http://{}", listener.local_addr().expect("bound"));
+                let requests = Arc::new(Mutex::new(Vec::new()));
+                let respond: Arc<Responder> = Arc::new(respond);
+                let recorded = Arc::clone(&requests);
+                thread::spawn(move || {
+                        for stream in listener.incoming().map_while(Result::ok) {
+                                let (recorded, respond) = (Arc::clone(&recorded), Arc::clone(&respond));
+                                thread::spawn(move || handle(stream, &recorded, respond.as_ref()));
+                        }
+                });
+                Self { url, requests }
+        }
+
+        /// Requests received so far, in order.
+        pub fn requests(&self) -> Vec<Request> {
+                self.requests.lock().expect("no panics while recording").clone()
+        }
+}
+
+fn handle(stream: TcpStream, recorded: &Mutex<Vec<Request>>, respond: &Responder) {
+        let Some(request) = read_request(&stream) else {
+                return;
+        };
+        let (status, body) = respond(&request);
+        recorded.lock().expect("no panics while recording").push(request);
+        let response = format!(
+                "HTTP/1.1 {status} Stand-In\r\ncontent-type: text/html\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+        );
+        let _ = (&stream).write_all(response.as_bytes());
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let mut words = line.split_whitespace();
+        let (method, path) = (words.next()?.to_string(), words.next()?.to_string());
+        let mut headers = Vec::new();
+        loop {
+                line.clear();
+                reader.read_line(&mut line).ok()?;
+                let Some((name, value)) = line.trim_end().split_once(':') else {
+                        break;
+                };
+                headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+        let length: usize = headers
+                .iter()
+                .find(|(name, _)| name == "content-length")
+                .and_then(|(_, value)| value.parse().ok())
+                .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).ok()?;
+        Some(Request { method, path, headers, body: String::from_utf8_lossy(&body).into_owned() })
+}
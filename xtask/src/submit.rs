@@ -0,0 +1,403 @@
+//! `cargo xtask submit <N> <part> <answer>`: post an answer, and keep a history of every guess.
+//!
+//! Guesses are kept with the day's input, as `<input dir>/dayNN/guesses.toml`, and checked before anything is sent:
+//! an answer already found wrong, or outside the bounds set by "too high"/"too low" verdicts, is refused locally, as is
+//! any answer for a part already solved or while the site still asks to wait.
+
+use std::{fs,
+          path::{Path, PathBuf}};
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::{ErrKindXtask, Result},
+            site::SiteClient};
+
+/// Name of a day's guess history, next to its input.
+pub const HISTORY_FILE: &str = "guesses.toml";
+
+/// What the site made of an answer.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+        #[display("correct")]
+        Correct,
+        #[display("too high")]
+        TooHigh,
+        #[display("too low")]
+        TooLow,
+        /// Wrong, with no hint which way.
+        #[display("wrong")]
+        Wrong,
+        /// Not judged: sent before the wait after an earlier guess was over.
+        #[display("too soon")]
+        TooSoon,
+        /// Not judged: the part is solved already, or not yet unlocked.
+        #[display("not the current part")]
+        WrongLevel,
+        /// Not judged, as far as could be told from the response.
+        #[display("unrecognized response")]
+        Unrecognized,
+}
+impl Verdict {
+        /// Whether the answer was judged wrong.
+        pub fn is_wrong(self) -> bool {
+                matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+        }
+}
+
+/// The site's response to an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+        pub verdict: Verdict,
+        /// Seconds to wait before the next answer, if the site says.
+        pub wait:    Option<u64>,
+        /// The response's message, as text.
+        pub message: String,
+}
+
+/// Read the verdict (and any wait) from a response page.
+pub fn parse_response(html: &str) -> Response {
+        let message = article_text(html);
+        let verdict = if message.contains("That's the right answer") {
+                Verdict::Correct
+        } else if message.contains("your answer is too high") {
+                Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+                Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+                Verdict::Wrong
+        } else if message.contains("You gave an answer too recently") {
+                Verdict::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+                Verdict::WrongLevel
+        } else {
+                Verdict::Unrecognized
+        };
+        Response { verdict, wait: parse_wait(&message), message }
+}
+
+/// Text of the page's `<article>` (or of the whole page, if it has none), tags dropped & whitespace collapsed.
+fn article_text(html: &str) -> String {
+        let article = html
+                .split_once("<article")
+                .and_then(|(_, rest)| rest.split_once('>'))
+                .map(|(_, rest)| rest.split_once("</article>").map_or(rest, |(article, _)| article))
+                .unwrap_or(html);
+        let mut text = String::with_capacity(article.len());
+        let mut in_tag = false;
+        for ch in article.chars() {
+                match ch {
+                        '<' => in_tag = true,
+                        '>' => in_tag = false,
+                        _ if !in_tag => text.push(ch),
+                        _ => {}
+                }
+        }
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Seconds the site asks to wait: "You have 1m 34s left to wait" or "Please wait one minute".
+fn parse_wait(message: &str) -> Option<u64> {
+        if let Some((_, rest)) = message.split_once("You have ") {
+                let (left, _) = rest.split_once(" left to wait")?;
+                return left.split_whitespace().try_fold(0, |total, amount| {
+                        let (count, unit) = amount.split_at(amount.find(|ch: char| !ch.is_ascii_digit())?);
+                        let scale = match unit {
+                                "h" => 3600,
+                                "m" => 60,
+                                "s" => 1,
+                                _ => return None,
+                        };
+                        Some(total + count.parse::<u64>().ok()? * scale)
+                });
+        }
+        let (_, rest) = message.split_once("Please wait ")?;
+        let mut words = rest.split_whitespace();
+        let count = match words.next()? {
+                "one" => 1,
+                count => count.parse().ok()?,
+        };
+        let scale = match words.next()?.trim_end_matches('s') {
+                "minute" => 60,
+                "second" => 1,
+                _ => return None,
+        };
+        Some(count * scale)
+}
+
+/// A submitted answer, and what came of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+        pub part:    u8,
+        pub answer:  String,
+        pub verdict: Verdict,
+        /// When it was submitted, in seconds since the Unix epoch.
+        pub at:      u64,
+        /// Seconds the site asked to wait after it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub wait:    Option<u64>,
+}
+
+/// Every answer submitted for a day, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+        #[serde(default, rename = "guess")]
+        pub guesses: Vec<Guess>,
+}
+impl History {
+        /// The history at `path`; empty if there is none yet.
+        pub fn load(path: &Path) -> Result<Self> {
+                match fs::read_to_string(path) {
+                        Ok(text) => toml::from_str(&text).map_err(|source| ErrKindXtask::History {
+                                path:   path.to_path_buf(),
+                                source: Box::new(source),
+                        }),
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+                        Err(err) => Err(err.into()),
+                }
+        }
+
+        pub fn save(&self, path: &Path) -> Result<()> {
+                let toml = toml::to_string(self).expect("a history serializes as TOML");
+                fs::create_dir_all(path.parent().expect("a file in a day dir"))?;
+                fs::write(path, format!("# Answers submitted with `cargo xtask submit`, oldest first.\n\n{toml}"))?;
+                Ok(())
+        }
+
+        /// Range a part's answer must be in, from the "too high" & "too low" verdicts for it.
+        pub fn bounds(&self, part: u8) -> Bounds {
+                let judged = |verdict| {
+                        self.guesses
+                                .iter()
+                                .filter(move |guess| guess.part == part && guess.verdict == verdict)
+                                .filter_map(|guess| guess.answer.parse::<i128>().ok())
+                };
+                Bounds { above: judged(Verdict::TooLow).max(), below: judged(Verdict::TooHigh).min() }
+        }
+
+        /// Refuse an answer the history shows to be pointless to send (now, in seconds since the Unix epoch).
+        pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<()> {
+                let for_part = || self.guesses.iter().filter(|guess| guess.part == part);
+                if let Some(solved) = for_part().find(|guess| guess.verdict == Verdict::Correct) {
+                        return Err(ErrKindXtask::AlreadySolved { part, answer: solved.answer.clone() });
+                }
+                if let Some(until) = self.guesses.last().and_then(|last| Some(last.at + last.wait?)) {
+                        if until > now {
+                                return Err(ErrKindXtask::WaitFirst { seconds: until - now });
+                        }
+                }
+                if let Some(known) = for_part().find(|guess| guess.answer == answer && guess.verdict.is_wrong()) {
+                        return Err(ErrKindXtask::KnownWrong {
+                                part,
+                                answer: answer.to_string(),
+                                verdict: known.verdict,
+                        });
+                }
+                let bounds = self.bounds(part);
+                if answer.parse::<i128>().is_ok_and(|answer| !bounds.contains(answer)) {
+                        return Err(ErrKindXtask::OutOfBounds { part, answer: answer.to_string(), bounds });
+                }
+                Ok(())
+        }
+}
+
+/// Open range an answer must be in; an unknown bound is `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bounds {
+        /// Highest answer found too low.
+        pub above: Option<i128>,
+        /// Lowest answer found too high.
+        pub below: Option<i128>,
+}
+impl Bounds {
+        pub fn contains(&self, answer: i128) -> bool {
+                self.above.is_none_or(|above| answer > above) && self.below.is_none_or(|below| answer < below)
+        }
+}
+impl std::fmt::Display for Bounds {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match (self.above, self.below) {
+                        (Some(above), Some(below)) => write!(f, "above {above} and below {below}"),
+                        (Some(above), None) => write!(f, "above {above}"),
+                        (None, Some(below)) => write!(f, "below {below}"),
+                        (None, None) => write!(f, "unbounded"),
+                }
+        }
+}
+
+/// History path of a day's guesses.
+pub fn history_path(input_dir: &Path, day: u8) -> PathBuf {
+        input_dir.join(format!("day{day:02}")).join(HISTORY_FILE)
+}
+
+/// Submit an answer for a day's part, unless the day's history shows it pointless; the guess is recorded either way
+/// the site answers.
+///
+/// `now` is in seconds since the Unix epoch. The client is only made if the answer is to be sent.
+pub fn submit(
+        day: u8,
+        part: u8,
+        answer: &str,
+        input_dir: &Path,
+        now: u64,
+        client: impl FnOnce() -> Result<SiteClient>,
+) -> Result<Response> {
+        let answer = answer.trim();
+        let path = history_path(input_dir, day);
+        let mut history = History::load(&path)?;
+        history.check(part, answer, now)?;
+
+        let response = parse_response(&client()?.submit(day, part, answer)?);
+        history.guesses.push(Guess {
+                part,
+                answer: answer.to_string(),
+                verdict: response.verdict,
+                at: now,
+                wait: response.wait,
+        });
+        history.save(&path)?;
+        Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+        use crate::stand_in::StandIn;
+
+        const CORRECT: &str = include_str!("../data/responses/correct.html");
+        const TOO_HIGH: &str = include_str!("../data/responses/too_high.html");
+        const TOO_LOW: &str = include_str!("../data/responses/too_low.html");
+        const WRONG: &str = include_str!("../data/responses/wrong.html");
+        const TOO_RECENT: &str = include_str!("../data/responses/too_recent.html");
+        const WRONG_LEVEL: &str = include_str!("../data/responses/wrong_level.html");
+
+        fn guess(part: u8, answer: &str, verdict: Verdict, at: u64, wait: Option<u64>) -> Guess {
+                Guess { part, answer: answer.to_string(), verdict, at, wait }
+        }
+
+        #[test]
+        fn parse_saved_responses() {
+                let parsed = |html| {
+                        let Response { verdict, wait, .. } = parse_response(html);
+                        (verdict, wait)
+                };
+                assert_eq!(parsed(CORRECT), (Verdict::Correct, None));
+                assert_eq!(parsed(TOO_HIGH), (Verdict::TooHigh, Some(60)));
+                assert_eq!(parsed(TOO_LOW), (Verdict::TooLow, Some(300)));
+                assert_eq!(parsed(WRONG), (Verdict::Wrong, Some(60)));
+                assert_eq!(parsed(TOO_RECENT), (Verdict::TooSoon, Some(94)));
+                assert_eq!(parsed(WRONG_LEVEL), (Verdict::WrongLevel, None));
+                assert_eq!(parsed("<html>Puzzle inputs differ by user.</html>"), (Verdict::Unrecognized, None));
+                assert_eq!(
+                        parse_response(WRONG_LEVEL).message,
+                        "You don't seem to be solving the right level. Did you already complete it? [Return to Day 6]"
+                );
+        }
+
+        #[test]
+        fn refuse_pointless_answers() {
+                let history = History {
+                        guesses: vec![
+                                guess(1, "4711", Verdict::Correct, 100, None),
+                                guess(2, "1000", Verdict::TooLow, 200, Some(60)),
+                                guess(2, "2000", Verdict::TooHigh, 300, Some(60)),
+                                guess(2, "1800", Verdict::TooHigh, 400, Some(300)),
+                                guess(2, "fifteen", Verdict::Wrong, 800, Some(60)),
+                        ],
+                };
+                assert_eq!(history.bounds(2), Bounds { above: Some(1000), below: Some(1800) });
+                assert!(matches!(history.check(1, "4712", 900), Err(ErrKindXtask::AlreadySolved { part: 1, .. })));
+                assert!(matches!(history.check(2, "1562", 840), Err(ErrKindXtask::WaitFirst { seconds: 20 })));
+                assert!(matches!(
+                        history.check(2, "fifteen", 900),
+                        Err(ErrKindXtask::KnownWrong { verdict: Verdict::Wrong, .. })
+                ));
+                let Err(out_of_bounds) = history.check(2, "1800", 900) else {
+                        panic!("1800 was too high");
+                };
+                assert_eq!(
+                        out_of_bounds.to_string(),
+                        "1800 was already submitted for part 2: too high; not sending it again"
+                );
+                let Err(out_of_bounds) = history.check(2, "999", 900) else {
+                        panic!("below a too low answer");
+                };
+                assert_eq!(
+                        out_of_bounds.to_string(),
+                        "999 is out of the recorded bounds for part 2: the answer is above 1000 and below 1800"
+                );
+                assert!(history.check(2, "1562", 900).is_ok());
+                assert!(history.check(2, "sixteen", 900).is_ok());
+        }
+
+        #[test]
+        fn history_round_trip() -> Result<()> {
+                let history = History {
+                        guesses: vec![
+                                guess(1, "4711", Verdict::Correct, 100, None),
+                                guess(2, "1800", Verdict::TooHigh, 400, Some(300)),
+                        ],
+                };
+                let path = std::env::temp_dir()
+                        .join(format!("xtask-submit-history-{}", std::process::id()))
+                        .join(HISTORY_FILE);
+                history.save(&path)?;
+                assert_eq!(fs::read_to_string(&path)?, indoc! {r#"
+                        # Answers submitted with `cargo xtask submit`, oldest first.
+
+                        [[guess]]
+                        part = 1
+                        answer = "4711"
+                        verdict = "correct"
+                        at = 100
+
+                        [[guess]]
+                        part = 2
+                        answer = "1800"
+                        verdict = "too-high"
+                        at = 400
+                        wait = 300
+                "#});
+                assert_eq!(History::load(&path)?, history);
+                fs::remove_dir_all(path.parent().expect("in a temp dir"))?;
+                assert_eq!(History::load(&path)?, History::default());
+                Ok(())
+        }
+
+        #[test]
+        fn submit_records_every_guess() -> Result<()> {
+                let site = StandIn::serve(|request| {
+                        let html = match request.body.as_str() {
+                                "level=1&answer=5000" => TOO_HIGH,
+                                "level=1&answer=4711" => CORRECT,
+                                _ => WRONG,
+                        };
+                        (200, html.to_string())
+                });
+                let input_dir = std::env::temp_dir().join(format!("xtask-submit-{}", std::process::id()));
+                let _ = fs::remove_dir_all(&input_dir);
+                let client = || SiteClient::new("53616c7465645f5f", &site.url);
+
+                assert_eq!(submit(6, 1, "5000", &input_dir, 1_000, client)?.verdict, Verdict::TooHigh);
+                let refused = submit(6, 1, "4711", &input_dir, 1_030, client);
+                assert!(matches!(refused, Err(ErrKindXtask::WaitFirst { seconds: 30 })));
+                let refused = submit(6, 1, "5001", &input_dir, 1_100, client);
+                assert!(matches!(refused, Err(ErrKindXtask::OutOfBounds { .. })));
+                assert_eq!(submit(6, 1, " 4711\n", &input_dir, 1_100, client)?.verdict, Verdict::Correct);
+
+                let requests = site.requests();
+                assert_eq!(requests.len(), 2);
+                assert_eq!((requests[1].method.as_str(), requests[1].path.as_str()), ("POST", "/2024/day/6/answer"));
+                assert_eq!(requests[1].header("content-type"), Some("application/x-www-form-urlencoded"));
+                assert_eq!(History::load(&history_path(&input_dir, 6))?.guesses, [
+                        guess(1, "5000", Verdict::TooHigh, 1_000, Some(60)),
+                        guess(1, "4711", Verdict::Correct, 1_100, None)
+                ]);
+                fs::remove_dir_all(&input_dir)?;
+                Ok(())
+        }
+}
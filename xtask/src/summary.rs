@@ -0,0 +1,203 @@
+//! `cargo xtask readme`: the README's table of contents & summary table, rebuilt from the code so neither drifts.
+//!
+//! Days & titles come from the `aoc` registry (`aoc list --format json`), run times from a release `aoc all --format
+//! json`, and answers from each day's `data/answers.toml`. Days are listed only if registered; a README section for a
+//! day that is not is kept, but left out of the table of contents and reported.
+
+use std::{fs, path::Path, process::Command, time::Duration};
+
+use aoc_core::{Answers, InputKind, Part};
+use owo_colors::OwoColorize as _;
+use serde::{Deserialize, de::DeserializeOwned};
+
+use crate::{error::{ErrKindXtask, Result},
+            readme, workspace_dir};
+
+/// Shown in place of an answer or time that is not known.
+const UNKNOWN: &str = "—";
+
+/// A registered day, as `aoc list --format json` prints it.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Listing {
+        pub day:   u8,
+        pub title: String,
+}
+
+/// The parts of an `aoc all --format json` run report the summary uses.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Report {
+        pub day:        u8,
+        pub part:       u8,
+        pub input:      String,
+        pub status:     String,
+        pub parse_secs: Option<f64>,
+        pub solve_secs: Option<f64>,
+}
+
+/// One row of the summary table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySummary {
+        pub day:     u8,
+        pub title:   String,
+        /// Recorded answers to each part on full input.
+        pub answers: [Option<String>; 2],
+        /// Parse & solve time of both parts on full input; `None` unless both ran OK.
+        pub runtime: Option<Duration>,
+}
+impl DaySummary {
+        /// Summary of a registered day, from its full-input runs & recorded answers.
+        pub fn new(listing: Listing, reports: &[Report], answers: &Answers) -> Self {
+                let runtime = [1, 2]
+                        .map(|part| {
+                                reports.iter()
+                                        .find(|report| {
+                                                (report.day, report.part) == (listing.day, part)
+                                                        && report.input == InputKind::Full.to_string()
+                                        })
+                                        .filter(|report| report.status == "ok")
+                                        .and_then(|report| Some(report.parse_secs? + report.solve_secs?))
+                        })
+                        .into_iter()
+                        .sum::<Option<f64>>()
+                        .map(Duration::from_secs_f64);
+                DaySummary {
+                        day: listing.day,
+                        title: listing.title,
+                        answers: [Part::Part1, Part::Part2]
+                                .map(|part| answers.get(part, InputKind::Full).map(str::to_string)),
+                        runtime,
+                }
+        }
+}
+
+/// Summary table in markdown: each day's puzzle, notes, answers & run time.
+pub fn markdown_table(days: &[DaySummary]) -> String {
+        let mut table = String::from("| Day | Puzzle | Notes | Part 1 | Part 2 | Runtime |\n");
+        table.push_str("| --: | :----- | :---- | -----: | -----: | ------: |\n");
+        for summary in days {
+                let DaySummary { day, title, answers, runtime } = summary;
+                let [part1, part2] = answers.each_ref().map(|answer| answer.as_deref().unwrap_or(UNKNOWN));
+                let runtime = runtime.map_or_else(|| UNKNOWN.to_string(), |runtime| format!("{runtime:.2?}"));
+                table.push_str(&format!(
+                        "| {day} | [{title}](https://adventofcode.com/2024/day/{day}) | [day{day:02}](crates/day{day:02}/README.md) \
+                         | {part1} | {part2} | {runtime} |\n"
+                ));
+        }
+        table
+}
+
+/// Rebuild the README's table of contents & summary table from the registered days, giving any new day a section.
+pub fn readme() -> Result<()> {
+        let workspace = workspace_dir();
+        let listings: Vec<Listing> = aoc_json(&workspace, &["list"])?;
+        println!("{} every registered day, for run times", "running".green());
+        let reports: Vec<Report> = aoc_json(&workspace, &["all"])?;
+
+        let mut summaries = Vec::with_capacity(listings.len());
+        for listing in listings {
+                let answers = read_answers(&workspace.join(format!("crates/day{:02}/data/answers.toml", listing.day)))?;
+                summaries.push(DaySummary::new(listing, &reports, &answers));
+        }
+
+        let path = workspace.join("README.md");
+        let mut text = fs::read_to_string(&path)?;
+        let missing = |anchor| ErrKindXtask::MissingAnchor { anchor, path: path.clone() };
+        for summary in &summaries {
+                text = readme::add_day(&text, summary.day, &summary.title).ok_or_else(|| missing(readme::TOC_END))?;
+        }
+        text = readme::set_summary(&text, &markdown_table(&summaries)).ok_or_else(|| missing(readme::SUMMARY_START))?;
+        let days: Vec<(u8, &str)> = summaries
+                .iter()
+                .map(|summary| (summary.day, summary.title.as_str()))
+                .collect();
+        text = readme::set_toc(&text, &days).ok_or_else(|| missing(readme::TOC_START))?;
+        fs::write(&path, text.as_bytes())?;
+        println!("{} {}", "rebuilt".green(), path.display());
+
+        for day in readme::section_days(&text) {
+                if !days.iter().any(|&(registered, _)| registered == day) {
+                        println!(
+                                "{} Day {day} has a README section but no registered crate; it is left out of the contents",
+                                "note:".yellow()
+                        );
+                }
+        }
+        Ok(())
+}
+
+/// A day's recorded answers; none if it has no answers file.
+fn read_answers(path: &Path) -> Result<Answers> {
+        match fs::read_to_string(path) {
+                Ok(text) => Answers::from_toml(&text).map_err(|source| ErrKindXtask::Answers {
+                        path:   path.to_path_buf(),
+                        source: Box::new(source),
+                }),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+                Err(err) => Err(err.into()),
+        }
+}
+
+/// Output of an `aoc` command run with `--format json` from a release build.
+fn aoc_json<T: DeserializeOwned>(workspace: &Path, args: &[&str]) -> Result<T> {
+        let output = Command::new(env!("CARGO"))
+                .args(["run", "--release", "--quiet", "--package", "aoc", "--"])
+                .args(args)
+                .args(["--format", "json"])
+                .current_dir(workspace)
+                .output()?;
+        if !output.status.success() {
+                return Err(ErrKindXtask::Command {
+                        command: format!("aoc {} --format json", args.join(" ")),
+                        status:  output.status,
+                });
+        }
+        Ok(serde_json::from_slice(&output.stdout)?)
+}
+
+#[cfg(test)]
+mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn summary_of_runs_and_answers() {
+                let report = |part, input: &str, status: &str, secs: Option<f64>| Report {
+                        day: 6,
+                        part,
+                        input: input.to_string(),
+                        status: status.to_string(),
+                        parse_secs: secs,
+                        solve_secs: secs,
+                };
+                let reports = [
+                        report(1, "example", "ok", Some(4.0)),
+                        report(1, "full", "ok", Some(0.25)),
+                        report(2, "full", "ok", Some(0.5)),
+                ];
+                let answers = Answers::from_toml(indoc! {r#"
+                        [part1]
+                        example = "41"
+                        full = "4711"
+                "#})
+                .expect("valid answers");
+                let listing = Listing { day: 6, title: "Guard Gallivant".to_string() };
+
+                let summary = DaySummary::new(listing.clone(), &reports, &answers);
+                assert_eq!(summary, DaySummary {
+                        day:     6,
+                        title:   "Guard Gallivant".to_string(),
+                        answers: [Some("4711".to_string()), None],
+                        runtime: Some(Duration::from_millis(1500)),
+                });
+                let panicked = [report(1, "full", "ok", Some(0.25)), report(2, "full", "panic", None)];
+                assert_eq!(DaySummary::new(listing, &panicked, &answers).runtime, None);
+
+                assert_eq!(markdown_table(&[summary]), indoc! {"
+                        | Day | Puzzle | Notes | Part 1 | Part 2 | Runtime |
+                        | --: | :----- | :---- | -----: | -----: | ------: |
+                        | 6 | [Guard Gallivant](https://adventofcode.com/2024/day/6) | [day06](crates/day06/README.md) | 4711 | — | 1.50s |
+                "});
+        }
+}